@@ -0,0 +1,31 @@
+//! Hosts a LAN derby without the game, e.g. `cargo run --bin derby_host -- 0.0.0.0:7878`
+
+#[path = "../derby/host.rs"]
+mod host;
+#[path = "../logging.rs"]
+mod logging;
+#[path = "../derby/protocol.rs"]
+mod protocol;
+
+use host::Host;
+use protocol::DEFAULT_PORT;
+
+fn main() {
+    let addr = std::env::args().nth(1).unwrap_or(format!("0.0.0.0:{}", DEFAULT_PORT));
+
+    let host = match Host::bind(&addr) {
+        Ok(host) => host,
+        Err(e) => {
+            eprintln!("Failed to host on {}: {}", addr, e);
+            return;
+        }
+    };
+    match host.local_addr() {
+        Ok(local) => println!("Derby host listening on {}", local),
+        Err(_) => println!("Derby host listening on {}", addr),
+    }
+
+    if let Err(e) = host.run() {
+        eprintln!("Derby host stopped: {}", e);
+    }
+}
@@ -0,0 +1,32 @@
+//! A reference leaderboard server for trying out online scores locally,
+//! e.g. `MINI_FISHER_SECRET=hunter2 cargo run --bin leaderboard_server -- 0.0.0.0:8080`
+
+#[path = "../online/protocol.rs"]
+mod protocol;
+#[path = "../online/server.rs"]
+mod server;
+
+use protocol::{DEFAULT_PORT, DEV_SECRET};
+use server::ScoreServer;
+
+fn main() {
+    let addr = std::env::args().nth(1).unwrap_or(format!("127.0.0.1:{}", DEFAULT_PORT));
+    let secret = std::env::var("MINI_FISHER_SECRET").unwrap_or_else(|_| {
+        println!("MINI_FISHER_SECRET isn't set, using the development secret");
+        DEV_SECRET.to_string()
+    });
+
+    let server = match ScoreServer::bind(&addr, &secret) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("Failed to listen on {}: {}", addr, e);
+            return;
+        }
+    };
+    match server.local_addr() {
+        Some(local) => println!("Leaderboard server listening on http://{}", local),
+        None => println!("Leaderboard server listening on {}", addr),
+    }
+
+    server.run();
+}
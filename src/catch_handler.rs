@@ -1,9 +1,30 @@
 use std::sync::{Arc, Mutex};
 use chrono::{DateTime, Duration, Local};
 use rand::{Rng, thread_rng};
-use crate::data::fish::Fish;
-use crate::data::userfile::update_userfile;
-//use crate::say;
+use crate::data::achievements::{AchievementData, AchievementEvent, unlock_text};
+use crate::data::aquarium::{collect_income, KeptFish};
+use crate::data::boats::{BoatData, charge_upkeep};
+use crate::data::collections::CollectionData;
+use crate::data::components::ComponentData;
+use crate::data::fish::{Fish, FishData, SelectError};
+use crate::data::history::{append_histories, append_history, CatchOutcome, CatchRecord};
+use crate::data::loot::{add_loot, LootData};
+use crate::data::money::Money;
+use crate::data::random_events::RandomEventData;
+use crate::data::rods::{Rod, RodData};
+use crate::data::tackle::TackleData;
+use crate::data::skills::{level_for_xp, xp_for_catch, SkillData};
+use crate::data::tournaments::{record_player_catch, update_tournament, TournamentData};
+use crate::data::userfile::{update_userfile, UserValues};
+use crate::derby::client::DerbyClient;
+use crate::derby::protocol::CatchReport;
+use crate::{nay, say, yay};
+
+const WEIGHT_ADD_TIME: f32 = 0.05;
+/// the auto-fisher's catch chance relative to casting by hand
+pub const AUTO_FISHER_EFFICIENCY: f32 = 0.75;
+const MAX_OFFLINE_HOURS: i64 = 8;
+const MAX_OFFLINE_CASTS: u32 = 2000;
 
 #[derive(Clone)]
 pub struct CatchData {
@@ -18,7 +39,24 @@ pub struct CatchData {
     pub fish: Option<Fish>,
 
     pub caught: bool,
-    pub was_turtle: bool,
+    /// the image of the random event that happened on the last cast
+    pub event_image: Option<egui::ImageSource<'static>>,
+    /// the last fish caught, until it's kept or another cast starts
+    pub last_catch: Option<KeptFish>,
+
+    pub auto_fish: bool,
+    /// the LAN derby the player has joined, catches are shared with it
+    pub derby: Option<Arc<DerbyClient>>,
+    /// set while connecting to a derby on its own thread
+    pub derby_joining: bool,
+    /// the port of the derby this game is hosting, cleared if the host stops
+    pub derby_hosting: Option<u16>,
+    /// set until the time the game was closed has been simulated
+    pub catching_up: bool,
+    /// what the auto-fisher did while the game was closed, until the UI shows it
+    pub offline_summary: Option<OfflineSummary>,
+    /// when the running tournament ends, so it's paid out without the save being checked every second
+    pub tournament_ends_at: Option<i64>,
 
     pub running: bool
 }
@@ -37,6 +75,369 @@ pub fn reset(data: &mut CatchData) {
     }
 }
 
+/// A planned cast: the fish on the line, how long it takes and whether it will be caught
+pub struct Cast {
+    pub fish: Fish,
+    pub duration: Duration,
+    pub will_catch: bool,
+}
+
+impl Cast {
+    /// Plans a cast with the rod, `efficiency` scales the rod's catch chance
+    pub fn plan(rod: &Rod, fish_data: &FishData, efficiency: f32) -> Result<Self, SelectError> {
+        let fish = Fish::random_fish(fish_data, rod)?;
+
+        let weight_catch_time_add = (fish.weight - fish.fish_type.avg_weight as f32) * WEIGHT_ADD_TIME;
+        let duration = (rod.random_catch_time() + weight_catch_time_add) as i64;
+
+        let catch_chance = (rod.get_catch_chance() as f32 * efficiency) as u32;
+        let will_catch = thread_rng().gen_range(0..1000) <= catch_chance;
+
+        Ok(Self {
+            fish,
+            duration: Duration::seconds(duration),
+            will_catch,
+        })
+    }
+}
+
+/// Starts a cast with the player's current rod
+pub fn start_cast(data: &mut CatchData, rod: &Rod, fish_data: &FishData, efficiency: f32) {
+    let cast = match Cast::plan(rod, fish_data, efficiency) {
+        Ok(cast) => cast,
+        Err(e) => {
+            data.display_text = e.to_string();
+            return;
+        }
+    };
+
+    data.display_text = format!("You cast your {}!", rod);
+    data.cast = true;
+    data.caught = false;
+    data.last_catch = None;
+    data.cast_btn_txt = "Rod is cast!".to_string();
+    data.cast_time = Some(Local::now());
+    data.cast_duration = Some(cast.duration);
+    data.will_catch = cast.will_catch;
+    data.fish = Some(cast.fish);
+}
+
+pub struct CastResult {
+    pub outcome: CatchOutcome,
+    pub value: Money,
+    pub text: String,
+    /// true if this was the first time the species was caught
+    pub new_species: bool,
+    pub event_image: Option<egui::ImageSource<'static>>,
+}
+
+/// Every catalog resolving a cast reads, loaded once when resolving many casts in a row
+struct Catalogs {
+    rod_data: RodData,
+    fish_data: FishData,
+    achievement_data: AchievementData,
+    component_data: ComponentData,
+    random_event_data: RandomEventData,
+    loot_data: LootData,
+    collection_data: CollectionData,
+    tournament_data: TournamentData,
+    boat_data: BoatData,
+    tackle_data: TackleData,
+    skill_data: SkillData,
+}
+
+impl Catalogs {
+    fn load() -> Self {
+        Self {
+            rod_data: crate::rod_data(),
+            fish_data: crate::data::fish_data(),
+            achievement_data: crate::data::achievement_data(),
+            component_data: crate::data::component_data(),
+            random_event_data: crate::data::random_event_data(),
+            loot_data: crate::data::loot_data(),
+            collection_data: crate::data::collection_data(),
+            tournament_data: crate::data::tournament_data(),
+            boat_data: crate::data::boat_data(),
+            tackle_data: crate::data::tackle_data(),
+            skill_data: crate::data::skill_data(),
+        }
+    }
+
+    /// The player's rod, as `UserValues::get_rod` builds it
+    fn rod(&self, userfile: &UserValues) -> Rod {
+        userfile.get_rod(&self.rod_data, &self.component_data, &self.tackle_data, &self.boat_data, &self.skill_data)
+    }
+}
+
+/// Resolves a finished cast, updating the userfile, achievements, quests and history
+pub fn resolve_cast(fish: &Fish, will_catch: bool, cast_duration: Duration) -> CastResult {
+    let mut userfile = crate::read_userfile();
+    let catalogs = Catalogs::load();
+    let (mut result, record) = apply_cast(&mut userfile, &catalogs, fish, will_catch, cast_duration, Local::now());
+
+    // displayed fish earn a little over time
+    let income = collect_income(&mut userfile);
+    if income > Money::ZERO {
+        result.text.push_str(&format!("\n🐠 Your aquarium earned {}! 🐠", income));
+    }
+
+    // boat upkeep comes out of the player's earnings
+    if let Some(upkeep) = charge_upkeep(&mut userfile, &catalogs.boat_data) {
+        result.text.push_str(&format!("\n⛵ {} ⛵", upkeep));
+    }
+
+    update_userfile(userfile);
+    append_history(&record);
+    result
+}
+
+/// Resolves a cast that ended at `now` against the userfile in memory, returning what happened and the record to log
+fn apply_cast(userfile: &mut UserValues, catalogs: &Catalogs, fish: &Fish, will_catch: bool,
+              cast_duration: Duration, now: DateTime<Local>) -> (CastResult, CatchRecord) {
+    let rod_data = &catalogs.rod_data;
+    let rod = catalogs.rod(userfile);
+
+    let fishdata = &catalogs.fish_data;
+    let achievement_data = &catalogs.achievement_data;
+
+    // random events only happen to fish that would have been landed
+    let event = if will_catch {
+        catalogs.random_event_data.roll(fish, &rod).cloned()
+    } else {
+        None
+    };
+
+    let value_multiplier = rod.bonuses.value_multiplier * event.as_ref().map_or(1.0, |e| e.value_multiplier());
+    let value = fish.get_value(fishdata).scale(value_multiplier);
+    let mut new_species = false;
+
+    let timestamp = now.timestamp();
+    userfile.last_seen = timestamp;
+
+    // check if the fish is too heavy
+    let (outcome, mut text) = if fish.weight as u32 > rod.get_weight_limit() {
+        userfile.line_breaks += 1;
+        userfile.records.entry(fish.fish_type.id.clone()).or_default().record_loss();
+        let unlocked = achievement_data.check(&AchievementEvent::LineBreak(fish.clone()), userfile, fishdata, timestamp);
+
+        (CatchOutcome::LineBroke,
+         format!("Your line broke! The {}lb {} was too heavy!{}", fish.weight, fish, unlock_text(&unlocked)))
+    } else if let Some((event, lost)) = event.as_ref().and_then(|e| e.lost_outcome().map(|lost| (e, lost))) {
+        // the fish is taken or gets away
+        *userfile.random_events.entry(event.id.clone()).or_insert(0) += 1;
+        userfile.records.entry(fish.fish_type.id.clone()).or_default().record_loss();
+        let unlocked = achievement_data.check(
+            &AchievementEvent::RandomEvent { id: event.id.clone(), fish: fish.clone() }, userfile, fishdata, timestamp);
+
+        (lost, format!("{}{}", event.describe(fish), unlock_text(&unlocked)))
+    } else if will_catch {
+        userfile.fish_caught += 1;
+        userfile.money += value;
+        if !userfile.has_seen.contains(&fish.fish_type.id) {
+            userfile.has_seen.push(fish.fish_type.id.clone());
+            new_species = true;
+        }
+        if !userfile.seen_this_prestige.contains(&fish.fish_type.id) {
+            userfile.seen_this_prestige.push(fish.fish_type.id.clone());
+        }
+        userfile.records.entry(fish.fish_type.id.clone()).or_default().record_catch(fish, value, timestamp);
+        let unlocked = achievement_data.check(&AchievementEvent::Catch(fish.clone()), userfile, fishdata, timestamp);
+
+        // rod components
+        let drop = catalogs.component_data.roll_drop(fish)
+            .map(|component| {
+                *userfile.components.entry(component.name.clone()).or_insert(0) += 1;
+                format!("\n🔧 The {} had a {} with it! 🔧", fish.fish_type, component.name)
+            })
+            .unwrap_or_default();
+
+        (CatchOutcome::Caught,
+         format!("You caught a {} {} at {}lbs!{}{}", value, fish, fish.weight, drop, unlock_text(&unlocked)))
+    } else {
+        userfile.records.entry(fish.fish_type.id.clone()).or_default().record_loss();
+
+        (CatchOutcome::Escaped,
+         format!("A {}lbs {} got away! Better luck next time!", fish.weight, fish))
+    };
+
+    if outcome == CatchOutcome::Caught {
+        // angler experience
+        let (old_level, _) = level_for_xp(userfile.xp);
+        userfile.xp += xp_for_catch(fish);
+        let (new_level, _) = level_for_xp(userfile.xp);
+        if new_level > old_level {
+            text.push_str(&format!("\n⭐ You reached angler level {}! ⭐", new_level));
+        }
+
+        // something else happened while reeling it in
+        if let Some(event) = &event {
+            event.apply(userfile);
+            *userfile.random_events.entry(event.id.clone()).or_insert(0) += 1;
+            let unlocked = achievement_data.check(
+                &AchievementEvent::RandomEvent { id: event.id.clone(), fish: fish.clone() }, userfile, fishdata, timestamp);
+            text.push_str(&format!("\n{}{}", event.describe(fish), unlock_text(&unlocked)));
+        }
+
+        // quest progress
+        userfile.quests.refresh(fishdata, rod_data, &rod, now);
+        for quest in userfile.quests.record_catch(fish, value) {
+            let reward = userfile.claim_reward(&quest.reward, rod_data);
+            text.push_str(&format!("\n📜 Quest complete: {}! ({}) 📜", quest.objective.describe(fishdata), reward));
+        }
+
+        // event currency
+        for event in &fishdata.events {
+            *userfile.event_currency.entry(event.currency.clone()).or_insert(0) += 1;
+        }
+    }
+
+    // the rest of the tournament fishes while the player does
+    if outcome == CatchOutcome::Caught {
+        record_player_catch(userfile, fish, value, &rod.location.id, &catalogs.tournament_data, timestamp);
+    }
+    if let Some(message) = update_tournament(userfile, &catalogs.tournament_data, &catalogs.rod_data,
+                                             fishdata, &catalogs.boat_data, timestamp) {
+        text.push_str(&format!("\n{}", message));
+    }
+
+    // junk and treasure come up with whatever was on the line
+    if outcome != CatchOutcome::LineBroke {
+        if let Some(item) = catalogs.loot_data.roll_drop(rod.get_depth()) {
+            add_loot(userfile, item);
+            text.push_str(&format!("\n📦 You also reeled in a {}! 📦", item));
+        }
+    }
+
+    // collection sets
+    for (name, reward) in catalogs.collection_data.check(userfile, rod_data, timestamp) {
+        text.push_str(&format!("\n📚 Collection complete: {}! ({}) 📚", name, reward));
+    }
+
+    let record = CatchRecord::new(fish, value, &rod, outcome, cast_duration.num_seconds(), timestamp);
+    let result = CastResult {
+        outcome,
+        value,
+        text,
+        new_species,
+        event_image: event.and_then(|e| e.image),
+    };
+    (result, record)
+}
+
+/// What happened while the game was closed
+#[derive(Debug, Clone)]
+pub struct OfflineSummary {
+    pub elapsed: Duration,
+    pub casts: u32,
+    pub caught: u32,
+    pub escaped: u32,
+    pub line_breaks: u32,
+    pub stolen: u32,
+    pub money: Money,
+    pub new_species: u32,
+}
+
+/// Simulates the auto-fisher for the time since the game was last open, each cast at the time it would have ended.
+/// Returns `None` if the auto-fisher isn't running or no time has passed.
+fn simulate_offline(userfile: &mut UserValues, catalogs: &Catalogs) -> Option<OfflineSummary> {
+    if !userfile.auto_fisher || !userfile.auto_fish_enabled || userfile.last_seen == 0 {
+        return None;
+    }
+
+    let now = Local::now();
+    let elapsed = Duration::seconds(now.timestamp() - userfile.last_seen)
+        .min(Duration::hours(MAX_OFFLINE_HOURS));
+    let started = now - elapsed;
+
+    // everything happens in memory, the history is written once at the end
+    let mut records = Vec::new();
+
+    let mut summary = OfflineSummary {
+        elapsed,
+        casts: 0,
+        caught: 0,
+        escaped: 0,
+        line_breaks: 0,
+        stolen: 0,
+        money: Money::ZERO,
+        new_species: 0,
+    };
+
+    let mut simulated = Duration::zero();
+    while summary.casts < MAX_OFFLINE_CASTS {
+        // the rod may change mid-simulation through quest rewards
+        let rod = catalogs.rod(userfile);
+        let Ok(cast) = Cast::plan(&rod, &catalogs.fish_data, AUTO_FISHER_EFFICIENCY) else {
+            break;
+        };
+
+        simulated = simulated + cast.duration.max(Duration::seconds(1));
+        if simulated > elapsed {
+            break;
+        }
+
+        let (result, record) = apply_cast(userfile, catalogs, &cast.fish, cast.will_catch, cast.duration, started + simulated);
+        records.push(record);
+        summary.casts += 1;
+        match result.outcome {
+            CatchOutcome::Caught => {
+                summary.caught += 1;
+                summary.money += result.value;
+            }
+            CatchOutcome::Escaped => summary.escaped += 1,
+            CatchOutcome::LineBroke => summary.line_breaks += 1,
+            CatchOutcome::Stolen => summary.stolen += 1,
+        }
+        if result.new_species {
+            summary.new_species += 1;
+        }
+    }
+
+    userfile.last_seen = now.timestamp();
+    append_histories(&records);
+
+    if summary.casts == 0 {
+        return None;
+    }
+
+    Some(summary)
+}
+
+/// Simulates the time the game was closed and collects what built up meanwhile,
+/// run on the scheduler's thread so the window opens straight away
+pub fn catch_up(data: &Mutex<CatchData>) {
+    let mut userfile = crate::read_userfile();
+    let catalogs = Catalogs::load();
+
+    // income and upkeep are settled once for the whole time away, the boat is paid for before it's used
+    if let Some(upkeep) = charge_upkeep(&mut userfile, &catalogs.boat_data) {
+        say!("{}", upkeep);
+    }
+    let summary = simulate_offline(&mut userfile, &catalogs);
+    let income = collect_income(&mut userfile);
+    if income > Money::ZERO {
+        yay!("Your aquarium earned {} while you were away!", income);
+    }
+    let tournament_ends_at = userfile.tournament.as_ref().map(|active| active.ends_at);
+    update_userfile(userfile);
+
+    let mut data = data.lock().unwrap();
+    data.offline_summary = summary;
+    data.tournament_ends_at = tournament_ends_at;
+    data.catching_up = false;
+}
+
+/// Pays out a tournament that has ended, unless a cast already did, returns a message if it did
+fn finish_tournament() -> Option<String> {
+    let mut userfile = crate::read_userfile();
+    userfile.tournament.as_ref()?;
+
+    let message = update_tournament(&mut userfile, &crate::data::tournament_data(), &crate::rod_data(),
+                                    &crate::data::fish_data(), &crate::data::boat_data(), Local::now().timestamp());
+    update_userfile(userfile);
+    message
+}
+
 pub fn schedule(data: Arc<Mutex<CatchData>>) {
     loop {
         std::thread::sleep(std::time::Duration::from_millis(1000));
@@ -46,8 +447,23 @@ pub fn schedule(data: Arc<Mutex<CatchData>>) {
             continue;
         }
 
+        // tournaments finish whether or not the player is casting
+        if data.tournament_ends_at.is_some_and(|ends_at| ends_at <= Local::now().timestamp()) {
+            data.tournament_ends_at = None;
+            if let Some(message) = finish_tournament() {
+                data.display_text = message;
+            }
+        }
+
+        if !data.cast && data.auto_fish {
+            let catalogs = Catalogs::load();
+            let rod = catalogs.rod(&crate::read_userfile());
+            start_cast(&mut data, &rod, &catalogs.fish_data, AUTO_FISHER_EFFICIENCY);
+            continue;
+        }
+
         if data.cast {
-            data.was_turtle = false;
+            data.event_image = None;
 
             // check if the cast has expired
             let current_elapsed = Local::now().signed_duration_since(data.cast_time.unwrap());
@@ -59,53 +475,28 @@ pub fn schedule(data: Arc<Mutex<CatchData>>) {
 
             let fish = data.fish.clone().unwrap();
 
-            let userfile = crate::read_userfile();
-
-            let rod_data = crate::rod_data();
-
-            let rod = userfile.get_rod(&rod_data);
+            let result = resolve_cast(&fish, data.will_catch, cast_duration);
 
-            drop(rod_data); // free up the wasted memory
+            data.display_text = result.text;
+            data.caught = result.outcome == CatchOutcome::Caught;
+            data.event_image = result.event_image;
+            if data.caught {
+                data.last_catch = Some(KeptFish::new(&fish, result.value));
 
-            // check if the fish is too heavy
-            if fish.weight as u32 > rod.get_weight_limit() {
-                data.display_text = format!("Your line broke! The {}lb {} was too heavy!", fish.weight, fish);
-
-                data.caught = false;
-                reset(&mut data);
-                continue;
-            }
-
-            if data.will_catch {
-                // turtle event 🐢🐢🐢
-                if thread_rng().gen_range(0..100) >= 98 {
-                    data.display_text = format!("🐢 A turtle stole your {}lb {}! 🐢", fish.weight, fish);
-                    data.caught = false;
-                    data.was_turtle = true;
-                    reset(&mut data);
-                    continue;
-                }
-
-                let fishdata = crate::data::fish_data();
-                let value = fish.get_value(&fishdata);
-
-                data.display_text = format!("You caught a ${} {} at {}lbs!", value, fish, fish.weight);
-                data.caught = true;
-                // update userfile
-                let mut userfile = crate::read_userfile();
-
-                userfile.fish_caught += 1;
-                userfile.money += value;
-                if !userfile.has_seen.contains(&fish.fish_type.name) {
-                    userfile.has_seen.push(fish.fish_type.name.clone());
+                if let Some(derby) = &data.derby {
+                    let report = CatchReport {
+                        species: fish.fish_type.to_string(),
+                        rarity: fish.rarity.to_string(),
+                        weight: fish.weight,
+                        value: result.value.dollars(),
+                    };
+                    if let Err(e) = derby.report_catch(report) {
+                        nay!("Failed to share your catch with the derby: {}", e);
+                    }
                 }
-                update_userfile(userfile);
-            } else {
-                data.display_text = format!("A {}lbs {} got away! Better luck next time!", fish.weight, fish);
-                data.caught = false;
             }
 
             reset(&mut data);
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,133 @@
+use egui::{Align2, Color32, FontId, Pos2, Rect, Sense, Stroke, Ui, Vec2};
+
+const ROW_HEIGHT: f32 = 18.0;
+const LABEL_WIDTH: f32 = 110.0;
+const VALUE_WIDTH: f32 = 55.0;
+
+pub const BAR_COLOR: Color32 = Color32::from_rgb(70, 140, 220);
+pub const EXPECTED_COLOR: Color32 = Color32::from_rgb(230, 160, 60);
+
+/// Draws a horizontal bar chart, one row per (label, value)
+pub fn bar_chart(ui: &mut Ui, bars: &[(String, f32)], format_value: impl Fn(f32) -> String) {
+    let width = ui.available_width();
+    let (response, painter) = ui.allocate_painter(Vec2::new(width, ROW_HEIGHT * bars.len() as f32), Sense::hover());
+    let text_color = ui.visuals().text_color();
+    let font = FontId::proportional(12.0);
+
+    let max = bars.iter().map(|(_, v)| *v).fold(0.0, f32::max).max(f32::EPSILON);
+    let bar_space = (width - LABEL_WIDTH - VALUE_WIDTH).max(1.0);
+
+    for (i, (label, value)) in bars.iter().enumerate() {
+        let top = response.rect.top() + ROW_HEIGHT * i as f32;
+        let left = response.rect.left();
+
+        painter.text(Pos2::new(left, top + ROW_HEIGHT / 2.0), Align2::LEFT_CENTER, label, font.clone(), text_color);
+
+        let bar = Rect::from_min_size(Pos2::new(left + LABEL_WIDTH, top + 3.0),
+                                      Vec2::new(bar_space * value / max, ROW_HEIGHT - 6.0));
+        painter.rect_filled(bar, 2.0, BAR_COLOR);
+
+        painter.text(Pos2::new(response.rect.right(), top + ROW_HEIGHT / 2.0), Align2::RIGHT_CENTER,
+                     format_value(*value), font.clone(), text_color);
+    }
+}
+
+/// Draws pairs of bars comparing an observed value against an expected one
+pub fn comparison_chart(ui: &mut Ui, bars: &[(String, f32, f32)], format_value: impl Fn(f32) -> String) {
+    let width = ui.available_width();
+    let (response, painter) = ui.allocate_painter(Vec2::new(width, ROW_HEIGHT * bars.len() as f32), Sense::hover());
+    let text_color = ui.visuals().text_color();
+    let font = FontId::proportional(12.0);
+
+    let max = bars.iter().map(|(_, o, e)| o.max(*e)).fold(0.0, f32::max).max(f32::EPSILON);
+    let bar_space = (width - LABEL_WIDTH - VALUE_WIDTH).max(1.0);
+
+    for (i, (label, observed, expected)) in bars.iter().enumerate() {
+        let top = response.rect.top() + ROW_HEIGHT * i as f32;
+        let left = response.rect.left();
+
+        painter.text(Pos2::new(left, top + ROW_HEIGHT / 2.0), Align2::LEFT_CENTER, label, font.clone(), text_color);
+
+        let half = (ROW_HEIGHT - 4.0) / 2.0;
+        let observed_bar = Rect::from_min_size(Pos2::new(left + LABEL_WIDTH, top + 2.0),
+                                               Vec2::new(bar_space * observed / max, half));
+        let expected_bar = Rect::from_min_size(Pos2::new(left + LABEL_WIDTH, top + 2.0 + half),
+                                               Vec2::new(bar_space * expected / max, half));
+        painter.rect_filled(observed_bar, 1.0, BAR_COLOR);
+        painter.rect_filled(expected_bar, 1.0, EXPECTED_COLOR);
+
+        painter.text(Pos2::new(response.rect.right(), top + ROW_HEIGHT / 2.0), Align2::RIGHT_CENTER,
+                     format!("{}/{}", format_value(*observed), format_value(*expected)), font.clone(), text_color);
+    }
+}
+
+/// Draws a line chart of the values, labelling the first and last points
+pub fn line_chart(ui: &mut Ui, values: &[f32], first_label: &str, last_label: &str) {
+    let width = ui.available_width();
+    let height = 100.0;
+    let (response, painter) = ui.allocate_painter(Vec2::new(width, height + ROW_HEIGHT), Sense::hover());
+    let text_color = ui.visuals().text_color();
+    let font = FontId::proportional(12.0);
+
+    let chart = Rect::from_min_size(response.rect.min, Vec2::new(width, height));
+    painter.rect_stroke(chart, 2.0, Stroke::new(1.0, ui.visuals().weak_text_color()));
+
+    let max = values.iter().cloned().fold(0.0, f32::max).max(f32::EPSILON);
+    let step = if values.len() > 1 { width / (values.len() - 1) as f32 } else { 0.0 };
+
+    let points: Vec<Pos2> = values.iter().enumerate()
+        .map(|(i, v)| Pos2::new(chart.left() + step * i as f32, chart.bottom() - (v / max) * (height - 4.0)))
+        .collect();
+
+    for pair in points.windows(2) {
+        painter.line_segment([pair[0], pair[1]], Stroke::new(2.0, BAR_COLOR));
+    }
+    for point in &points {
+        painter.circle_filled(*point, 2.5, BAR_COLOR);
+    }
+
+    painter.text(Pos2::new(chart.right() - 2.0, chart.top() + 2.0), Align2::RIGHT_TOP,
+                 format!("max {}", max.round()), font.clone(), text_color);
+    painter.text(Pos2::new(chart.left(), chart.bottom() + 2.0), Align2::LEFT_TOP, first_label, font.clone(), text_color);
+    painter.text(Pos2::new(chart.right(), chart.bottom() + 2.0), Align2::RIGHT_TOP, last_label, font, text_color);
+}
+
+pub const WATER_COLOR: Color32 = Color32::from_rgb(30, 80, 130);
+
+/// Draws a tank of fish swimming back and forth, one (size 0.0 to 1.0, color) per fish.
+/// `time` is in seconds and drives the animation.
+pub fn tank(ui: &mut Ui, fish: &[(f32, Color32)], time: f64) {
+    let width = ui.available_width();
+    let height = 160.0;
+    let (response, painter) = ui.allocate_painter(Vec2::new(width, height), Sense::hover());
+    let tank = response.rect;
+
+    painter.rect_filled(tank, 4.0, WATER_COLOR);
+    painter.rect_stroke(tank, 4.0, Stroke::new(2.0, ui.visuals().weak_text_color()));
+
+    let lanes = fish.len().max(1) as f32;
+    for (i, (size, color)) in fish.iter().enumerate() {
+        let radius = 5.0 + size.clamp(0.0, 1.0) * 10.0;
+        let swim_width = (width - radius * 4.0).max(1.0);
+
+        // each fish swims at its own speed and starts at its own point
+        let speed = 0.05 + 0.02 * (i % 3) as f64;
+        let phase = (time * speed + i as f64 * 0.37).fract() as f32;
+        // go right for the first half of the trip, then back
+        let (progress, facing) = if phase < 0.5 { (phase * 2.0, 1.0) } else { (2.0 - phase * 2.0, -1.0) };
+
+        let x = tank.left() + radius * 2.0 + swim_width * progress;
+        let y = tank.top() + height * (i as f32 + 0.5) / lanes;
+        let body = Pos2::new(x, y);
+
+        let tail_base = Pos2::new(x - facing * radius, y);
+        let tail = vec![
+            tail_base,
+            Pos2::new(tail_base.x - facing * radius, y - radius * 0.7),
+            Pos2::new(tail_base.x - facing * radius, y + radius * 0.7),
+        ];
+        painter.add(egui::Shape::convex_polygon(tail, *color, Stroke::NONE));
+        painter.circle_filled(body, radius, *color);
+        painter.circle_filled(Pos2::new(x + facing * radius * 0.5, y - radius * 0.25), radius * 0.15, Color32::BLACK);
+    }
+}
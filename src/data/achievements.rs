@@ -0,0 +1,108 @@
+use crate::data::fish::{Fish, FishData, FishRarity};
+use crate::data::money::Money;
+use crate::data::userfile::UserValues;
+
+/// Something that happened in the game which may unlock an achievement
+#[derive(Debug, Clone)]
+pub enum AchievementEvent {
+    Catch(Fish),
+    LineBreak(Fish),
+    /// a random event happened while landing a fish
+    RandomEvent { id: String, fish: Fish },
+    Purchase(String),
+}
+
+#[derive(Debug, Clone)]
+pub enum AchievementCondition {
+    /// catch a fish of at least this rarity
+    CatchRarity(FishRarity),
+    /// catch a specific species, by id
+    CatchSpecies(String),
+    /// catch a fish weighing at least this many lbs
+    CatchWeight(u32),
+    /// total fish caught
+    FishCaught(u32),
+    /// unique species seen
+    UniqueFish(u32),
+    /// every regular fish has been seen, event and prestige fish aren't needed
+    BestiaryComplete,
+    /// times a random event has happened
+    EventCount { event: String, count: u32 },
+    /// have a random event happen to a fish of at least this rarity
+    EventRarity { event: String, rarity: FishRarity },
+    /// lines broken by heavy fish
+    LineBreaks(u32),
+    /// break your line on a fish weighing at least this many lbs
+    LineBreakWeight(u32),
+    /// rods bought from the shop
+    RodsBought(u32),
+    /// buy a specific rod, by id
+    BuyRod(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Achievement {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub reward: Money,
+    pub condition: AchievementCondition,
+}
+
+impl Achievement {
+    pub fn is_met(&self, event: &AchievementEvent, userfile: &UserValues, fish_data: &FishData) -> bool {
+        match (&self.condition, event) {
+            (AchievementCondition::CatchRarity(rarity), AchievementEvent::Catch(fish)) => fish.rarity.ident() >= rarity.ident(),
+            (AchievementCondition::CatchSpecies(id), AchievementEvent::Catch(fish)) => &fish.fish_type.id == id,
+            (AchievementCondition::CatchWeight(weight), AchievementEvent::Catch(fish)) => fish.weight >= *weight as f32,
+            (AchievementCondition::FishCaught(count), _) => userfile.fish_caught >= *count,
+            (AchievementCondition::UniqueFish(count), _) => userfile.has_seen.len() as u32 >= *count,
+            (AchievementCondition::BestiaryComplete, _) => fish_data.regular_fish().all(|f| userfile.has_seen.contains(&f.id)),
+            (AchievementCondition::EventCount { event, count }, _) =>
+                userfile.random_events.get(event).copied().unwrap_or(0) >= *count,
+            (AchievementCondition::EventRarity { event, rarity }, AchievementEvent::RandomEvent { id, fish }) =>
+                id == event && fish.rarity.ident() >= rarity.ident(),
+            (AchievementCondition::LineBreaks(count), _) => userfile.line_breaks >= *count,
+            (AchievementCondition::LineBreakWeight(weight), AchievementEvent::LineBreak(fish)) => fish.weight >= *weight as f32,
+            (AchievementCondition::RodsBought(count), _) => userfile.rods_bought >= *count,
+            (AchievementCondition::BuyRod(name), AchievementEvent::Purchase(bought)) => bought == name,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AchievementData {
+    pub achievements: Vec<Achievement>,
+}
+
+impl AchievementData {
+    /// Unlocks every achievement met by this event, paying out their rewards.
+    /// The caller is responsible for saving the userfile afterwards.
+    pub fn check(&self, event: &AchievementEvent, userfile: &mut UserValues, fish_data: &FishData, now: i64) -> Vec<Achievement> {
+        let mut unlocked = Vec::new();
+
+        for achievement in &self.achievements {
+            if userfile.achievements.contains_key(&achievement.id) {
+                continue;
+            }
+
+            if !achievement.is_met(event, userfile, fish_data) {
+                continue;
+            }
+
+            userfile.achievements.insert(achievement.id.clone(), now);
+            userfile.money += achievement.reward;
+            unlocked.push(achievement.clone());
+        }
+
+        unlocked
+    }
+}
+
+/// Formats newly unlocked achievements for the catch display text
+pub fn unlock_text(unlocked: &[Achievement]) -> String {
+    unlocked.iter()
+        .map(|a| format!("\n🏆 Achievement unlocked: {}! (+{}) 🏆", a.name, a.reward))
+        .collect()
+}
@@ -0,0 +1,242 @@
+use std::fmt::Display;
+use chrono::{Duration, Local};
+use serde::{Deserialize, Serialize};
+use crate::data::fish::{Fish, FishData, FishRarity};
+use crate::data::money::Money;
+use crate::data::userfile::UserValues;
+
+const BASE_SLOTS: u32 = 3;
+pub const MAX_SLOTS: u32 = 12;
+const SLOT_COST: Money = Money::new(1000);
+/// fraction of a displayed fish's value earned every hour, only rare and better fish earn income
+const INCOME_PER_HOUR: f32 = 0.02;
+/// income stops building up after this long without being collected
+const MAX_INCOME_HOURS: i64 = 24;
+
+/// A catch kept in the aquarium instead of being sold
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KeptFish {
+    /// species id
+    pub species: String,
+    pub rarity: FishRarity,
+    pub weight: f32,
+    /// what the fish sold for when it was caught
+    pub value: Money,
+    pub kept_at: i64,
+}
+
+impl KeptFish {
+    pub fn new(fish: &Fish, value: Money) -> Self {
+        Self {
+            species: fish.fish_type.id.clone(),
+            rarity: fish.rarity.clone(),
+            weight: fish.weight,
+            value,
+            kept_at: Local::now().timestamp(),
+        }
+    }
+
+    pub fn hourly_income(&self) -> f32 {
+        if self.rarity.ident() < FishRarity::Rare.ident() {
+            return 0.0;
+        }
+        self.value.as_f32() * INCOME_PER_HOUR
+    }
+
+    /// e.g. "Rare Bass"
+    pub fn describe(&self, fish_data: &FishData) -> String {
+        format!("{} {}", self.rarity, fish_data.species_name(&self.species))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Aquarium {
+    pub fish: Vec<KeptFish>,
+    pub slots: u32,
+    /// timestamp income was last paid out up to
+    pub last_income: i64,
+}
+
+impl Default for Aquarium {
+    fn default() -> Self {
+        Self {
+            fish: Vec::new(),
+            slots: BASE_SLOTS,
+            last_income: Local::now().timestamp(),
+        }
+    }
+}
+
+impl Aquarium {
+    /// The price of the next tank slot, `None` if the tank is fully upgraded
+    pub fn slot_cost(&self) -> Option<Money> {
+        if self.slots >= MAX_SLOTS {
+            return None;
+        }
+        let upgrades = self.slots - BASE_SLOTS + 1;
+        Some(SLOT_COST.times(u64::from(upgrades * upgrades)))
+    }
+
+    pub fn hourly_income(&self) -> f32 {
+        self.fish.iter().map(|f| f.hourly_income()).sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum AquariumError {
+    TankFull,
+    NoMoney,
+    MaxSlots,
+    NotFound,
+}
+
+impl Display for AquariumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AquariumError::TankFull => write!(f, "Your aquarium is full! Sell a fish or buy another slot."),
+            AquariumError::NoMoney => write!(f, "You don't have enough money!"),
+            AquariumError::MaxSlots => write!(f, "Your aquarium can't get any bigger!"),
+            AquariumError::NotFound => write!(f, "That fish isn't in your aquarium!"),
+        }
+    }
+}
+
+/// Keeps a caught fish instead of selling it, taking back the money it sold for
+pub fn keep_fish(userfile: &mut UserValues, fish: KeptFish) -> Result<(), AquariumError> {
+    if userfile.aquarium.fish.len() as u32 >= userfile.aquarium.slots {
+        return Err(AquariumError::TankFull);
+    }
+    userfile.money = userfile.money.checked_sub(fish.value).ok_or(AquariumError::NoMoney)?;
+    userfile.aquarium.fish.push(fish);
+
+    Ok(())
+}
+
+/// Sells a fish from the aquarium for what it was worth when caught
+pub fn sell_kept_fish(userfile: &mut UserValues, index: usize) -> Result<KeptFish, AquariumError> {
+    if index >= userfile.aquarium.fish.len() {
+        return Err(AquariumError::NotFound);
+    }
+
+    let fish = userfile.aquarium.fish.remove(index);
+    userfile.money += fish.value;
+
+    Ok(fish)
+}
+
+pub fn upgrade_tank(userfile: &mut UserValues) -> Result<u32, AquariumError> {
+    let cost = userfile.aquarium.slot_cost().ok_or(AquariumError::MaxSlots)?;
+    userfile.money = userfile.money.checked_sub(cost).ok_or(AquariumError::NoMoney)?;
+    userfile.aquarium.slots += 1;
+
+    Ok(userfile.aquarium.slots)
+}
+
+/// Pays out the income earned by displayed fish since it was last collected
+pub fn collect_income(userfile: &mut UserValues) -> Money {
+    let now = Local::now().timestamp();
+    let hour = Duration::hours(1).num_seconds();
+
+    // skip whatever is past the cap
+    userfile.aquarium.last_income = userfile.aquarium.last_income.max(now - hour * MAX_INCOME_HOURS);
+    let hours = (now - userfile.aquarium.last_income) / hour;
+    if hours <= 0 {
+        return Money::ZERO;
+    }
+
+    userfile.aquarium.last_income += hours * hour;
+    let income = Money::from_f32(userfile.aquarium.hourly_income() * hours as f32);
+    userfile.money += income;
+
+    income
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * 60;
+
+    fn kept(rarity: FishRarity, value: u64) -> KeptFish {
+        KeptFish {
+            species: "trout".to_string(),
+            rarity,
+            weight: 20.0,
+            value: Money::new(value),
+            kept_at: 0,
+        }
+    }
+
+    /// A tank holding one rare fish earning $20 an hour, last collected `hours_ago`
+    fn tank(hours_ago: f32) -> UserValues {
+        let mut userfile = UserValues::default();
+        userfile.aquarium.fish.push(kept(FishRarity::Rare, 1000));
+        userfile.aquarium.last_income = Local::now().timestamp() - (hours_ago * HOUR as f32) as i64;
+        userfile
+    }
+
+    #[test]
+    fn income_is_paid_in_whole_hours() {
+        let mut userfile = tank(2.5);
+        let last_income = userfile.aquarium.last_income;
+
+        assert_eq!(collect_income(&mut userfile), Money::new(40));
+        assert_eq!(userfile.money, Money::new(40));
+        // the half hour left over carries on to the next collection
+        assert_eq!(userfile.aquarium.last_income, last_income + 2 * HOUR);
+        assert_eq!(collect_income(&mut userfile), Money::ZERO);
+    }
+
+    #[test]
+    fn income_is_capped() {
+        let mut userfile = tank(100.0);
+        assert_eq!(collect_income(&mut userfile), Money::new(20 * MAX_INCOME_HOURS as u64));
+    }
+
+    #[test]
+    fn only_rare_fish_earn() {
+        let mut userfile = tank(5.0);
+        userfile.aquarium.fish = vec![kept(FishRarity::Common, 1000), kept(FishRarity::Uncommon, 1000)];
+        assert_eq!(collect_income(&mut userfile), Money::ZERO);
+    }
+
+    #[test]
+    fn slots_get_more_expensive() {
+        let mut aquarium = Aquarium::default();
+        let mut costs = Vec::new();
+        while let Some(cost) = aquarium.slot_cost() {
+            costs.push(cost.dollars());
+            aquarium.slots += 1;
+        }
+
+        assert_eq!(costs[..3], [1000, 4000, 9000]);
+        assert_eq!(costs.len() as u32, MAX_SLOTS - BASE_SLOTS);
+    }
+
+    #[test]
+    fn keeping_takes_back_the_sale() {
+        let mut userfile = UserValues { money: Money::new(150), ..Default::default() };
+
+        keep_fish(&mut userfile, kept(FishRarity::Rare, 100)).unwrap();
+        assert_eq!(userfile.money, Money::new(50));
+        assert!(matches!(keep_fish(&mut userfile, kept(FishRarity::Rare, 100)), Err(AquariumError::NoMoney)));
+        assert_eq!(userfile.aquarium.fish.len(), 1);
+
+        userfile.money = Money::new(1000);
+        userfile.aquarium.slots = 1;
+        assert!(matches!(keep_fish(&mut userfile, kept(FishRarity::Rare, 100)), Err(AquariumError::TankFull)));
+        assert_eq!(userfile.money, Money::new(1000));
+    }
+
+    #[test]
+    fn selling_refunds_the_fish() {
+        let mut userfile = UserValues::default();
+        userfile.aquarium.fish = vec![kept(FishRarity::Rare, 100), kept(FishRarity::Elusive, 300)];
+
+        let sold = sell_kept_fish(&mut userfile, 1).unwrap();
+        assert_eq!(sold.value, Money::new(300));
+        assert_eq!(userfile.money, Money::new(300));
+        assert_eq!(userfile.aquarium.fish.len(), 1);
+        assert!(matches!(sell_kept_fish(&mut userfile, 1), Err(AquariumError::NotFound)));
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt::Display;
+use chrono::{Duration, Local};
+use crate::data::catalog::{Catalog, Identified};
+use crate::data::locale::localized;
+use crate::data::money::Money;
+use crate::data::userfile::UserValues;
+
+/// fraction of a boat's price the shop pays to buy it back
+const RESALE_FRACTION: f32 = 0.5;
+
+/// A boat takes the angler further out, adding depth and unlocking locations
+#[derive(Debug, Clone)]
+pub struct Boat {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    /// locations up to this tier can be fished from this boat
+    pub tier: u32,
+    /// depth in ft added to the rod
+    pub depth: u32,
+    pub cost: Money,
+    /// charged once a day
+    pub upkeep: Money,
+}
+
+/// A place to fish, some fish can only be found at one location
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    /// the boat tier needed to get here, 0 for no boat
+    pub tier: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoatData {
+    pub boats: Catalog<Boat>,
+    /// the first location is the default, reachable without a boat
+    pub locations: Catalog<Location>,
+}
+
+impl Display for Boat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", localized(&self.id, &self.name))
+    }
+}
+
+impl Identified for Boat {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", localized(&self.id, &self.name))
+    }
+}
+
+impl Identified for Location {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Boat {
+    /// What the shop pays to buy this boat back
+    pub fn resale_value(&self) -> Money {
+        self.cost.scale(RESALE_FRACTION)
+    }
+}
+
+impl BoatData {
+    pub fn get_boat(&self, id: &str) -> Option<&Boat> {
+        self.boats.get(id)
+    }
+
+    pub fn get_location(&self, id: &str) -> Option<&Location> {
+        self.locations.get(id)
+    }
+
+    /// The display name of a location id, or the id itself if the location no longer exists
+    pub fn location_name(&self, id: &str) -> String {
+        self.locations.get(id).map_or(id.to_string(), |location| location.to_string())
+    }
+
+    pub fn default_location(&self) -> &Location {
+        &self.locations[0]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TravelError {
+    UnknownLocation,
+    NeedsBoat(u32),
+}
+
+impl Display for TravelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TravelError::UnknownLocation => write!(f, "That location doesn't exist!"),
+            TravelError::NeedsBoat(tier) => write!(f, "You need a tier {} boat with its upkeep paid to get there!", tier),
+        }
+    }
+}
+
+/// Charges a day of the boat's upkeep once it's due. Days missed while the game was closed or the
+/// boat was docked aren't charged, a docked boat only needs its next day paid to set out again.
+/// Returns a message if anything changed, the boat being docked is only mentioned once.
+pub fn charge_upkeep(userfile: &mut UserValues, boat_data: &BoatData) -> Option<String> {
+    let boat = boat_data.get_boat(userfile.boat.as_ref()?)?;
+    let now = Local::now().timestamp();
+    if userfile.upkeep_due > now {
+        return None;
+    }
+
+    match userfile.money.checked_sub(boat.upkeep) {
+        Some(left) => {
+            userfile.money = left;
+            userfile.upkeep_due = now + Duration::days(1).num_seconds();
+            userfile.boat_docked = false;
+            Some(format!("Paid {} upkeep for your {}.", boat.upkeep, boat))
+        }
+        None if !userfile.boat_docked => {
+            userfile.boat_docked = true;
+            Some(format!("Your {} is docked until you can pay its {} upkeep!", boat, boat.upkeep))
+        }
+        None => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owner(money: u64, upkeep_due: i64) -> UserValues {
+        UserValues {
+            money: Money::new(money),
+            boat: Some("rowboat".to_string()),
+            upkeep_due,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn missed_days_are_not_charged() {
+        let boat_data = crate::data::boat_data();
+        let upkeep = boat_data.get_boat("rowboat").unwrap().upkeep;
+        let mut userfile = owner(upkeep.dollars() * 10, Local::now().timestamp() - Duration::days(7).num_seconds());
+
+        assert!(charge_upkeep(&mut userfile, &boat_data).is_some());
+        assert_eq!(userfile.money, upkeep.times(9));
+        assert!(userfile.upkeep_due > Local::now().timestamp());
+
+        // nothing more until the next day
+        assert!(charge_upkeep(&mut userfile, &boat_data).is_none());
+        assert_eq!(userfile.money, upkeep.times(9));
+    }
+
+    #[test]
+    fn docking_is_only_mentioned_once() {
+        let boat_data = crate::data::boat_data();
+        let mut userfile = owner(0, Local::now().timestamp() - 1);
+
+        assert!(charge_upkeep(&mut userfile, &boat_data).is_some());
+        assert!(userfile.get_boat(&boat_data).is_none());
+        assert!(charge_upkeep(&mut userfile, &boat_data).is_none());
+
+        // paying a single day sets out again
+        userfile.money = Money::new(1_000_000);
+        assert!(charge_upkeep(&mut userfile, &boat_data).is_some());
+        assert!(userfile.get_boat(&boat_data).is_some());
+        assert!(!userfile.boat_docked);
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+use std::ops::Deref;
+
+/// Game data with a stable id that saves can refer to, even if its display name changes
+pub trait Identified {
+    fn id(&self) -> &str;
+    /// the default display name, which older saves stored instead of the id
+    fn name(&self) -> &str;
+}
+
+/// Entries kept in their data order, indexed by id for lookups
+#[derive(Debug, Clone)]
+pub struct Catalog<T> {
+    entries: Vec<T>,
+    index: HashMap<String, usize>,
+}
+
+impl<T: Identified> Catalog<T> {
+    pub fn new(entries: Vec<T>) -> Self {
+        let index = entries.iter().enumerate()
+            .map(|(i, entry)| (entry.id().to_string(), i))
+            .collect();
+
+        Self { entries, index }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&T> {
+        self.index.get(id).map(|&i| &self.entries[i])
+    }
+
+    /// Swaps a display name read from a save from before ids for the id it refers to
+    pub fn migrate(&self, key: &mut String) {
+        if self.index.contains_key(key.as_str()) {
+            return;
+        }
+        if let Some(entry) = self.entries.iter().find(|entry| entry.name() == key.as_str()) {
+            *key = entry.id().to_string();
+        }
+    }
+}
+
+impl<T> Deref for Catalog<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
@@ -0,0 +1,148 @@
+use crate::data::fish::FishRarity;
+use crate::data::quests::QuestReward;
+use crate::data::rods::RodData;
+use crate::data::userfile::UserValues;
+
+#[derive(Debug, Clone)]
+pub enum SetRequirement {
+    /// catch every one of these species
+    Species(Vec<String>),
+    /// reel in every one of these loot items
+    Items(Vec<String>),
+    /// catch one species at each of these rarities
+    Rarities { species: String, rarities: Vec<FishRarity> },
+}
+
+/// A group of catches that pays out a reward once it's complete
+#[derive(Debug, Clone)]
+pub struct CollectionSet {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub requirement: SetRequirement,
+    pub reward: QuestReward,
+}
+
+impl CollectionSet {
+    /// Returns (collected, total)
+    pub fn progress(&self, userfile: &UserValues) -> (u32, u32) {
+        match &self.requirement {
+            SetRequirement::Species(species) => {
+                let collected = species.iter().filter(|s| userfile.has_seen.contains(s)).count();
+                (collected as u32, species.len() as u32)
+            }
+            SetRequirement::Items(items) => {
+                let collected = items.iter().filter(|i| userfile.items_seen.contains(i)).count();
+                (collected as u32, items.len() as u32)
+            }
+            SetRequirement::Rarities { species, rarities } => {
+                let caught = userfile.records.get(species).map(|r| r.rarities.as_slice()).unwrap_or_default();
+                let collected = rarities.iter().filter(|rarity| caught.iter().any(|c| c.ident() == rarity.ident())).count();
+                (collected as u32, rarities.len() as u32)
+            }
+        }
+    }
+
+    pub fn is_complete(&self, userfile: &UserValues) -> bool {
+        let (collected, total) = self.progress(userfile);
+        collected >= total
+    }
+
+    /// The species a rarity set is for, `None` for species sets
+    pub fn rarity_species(&self) -> Option<&str> {
+        match &self.requirement {
+            SetRequirement::Rarities { species, .. } => Some(species),
+            SetRequirement::Species(_) | SetRequirement::Items(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionData {
+    pub sets: Vec<CollectionSet>,
+}
+
+impl CollectionData {
+    /// Completes any finished sets and pays out their rewards, returning (set name, reward text)
+    pub fn check(&self, userfile: &mut UserValues, rod_data: &RodData, now: i64) -> Vec<(String, String)> {
+        let mut completed = Vec::new();
+
+        for set in &self.sets {
+            if userfile.collections.contains_key(&set.id) || !set.is_complete(userfile) {
+                continue;
+            }
+
+            userfile.collections.insert(set.id.clone(), now);
+            let reward = userfile.claim_reward(&set.reward, rod_data);
+            completed.push((set.name.clone(), reward));
+        }
+
+        completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::fish::Fish;
+    use crate::data::money::Money;
+
+    fn set(id: &str, requirement: SetRequirement) -> CollectionSet {
+        CollectionSet {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            requirement,
+            reward: QuestReward::Money(Money::new(100)),
+        }
+    }
+
+    fn catch(userfile: &mut UserValues, rarity: FishRarity) {
+        let fish = Fish {
+            fish_type: crate::data::fish_data().fish.get("trout").unwrap().clone(),
+            rarity,
+            weight: 20.0,
+        };
+        userfile.records.entry("trout".to_string()).or_default().record_catch(&fish, Money::new(10), 0);
+    }
+
+    #[test]
+    fn sets_complete_once() {
+        let data = CollectionData {
+            sets: vec![set("pair", SetRequirement::Species(vec!["trout".to_string(), "bass".to_string()]))],
+        };
+        let mut userfile = UserValues { has_seen: vec!["trout".to_string()], ..Default::default() };
+
+        assert!(data.check(&mut userfile, &crate::data::rod_data(), 0).is_empty());
+        userfile.has_seen.push("bass".to_string());
+        assert_eq!(data.check(&mut userfile, &crate::data::rod_data(), 0).len(), 1);
+        assert_eq!(userfile.money, Money::new(100));
+
+        assert!(data.check(&mut userfile, &crate::data::rod_data(), 0).is_empty());
+        assert_eq!(userfile.money, Money::new(100));
+        assert!(userfile.collections.contains_key("pair"));
+    }
+
+    #[test]
+    fn rarity_sets_count_distinct_rarities() {
+        let rarities = set("trout_rarities", SetRequirement::Rarities {
+            species: "trout".to_string(),
+            rarities: vec![FishRarity::Common, FishRarity::Uncommon, FishRarity::Rare],
+        });
+        let mut userfile = UserValues::default();
+
+        for _ in 0..5 {
+            catch(&mut userfile, FishRarity::Common);
+        }
+        assert_eq!(rarities.progress(&userfile), (1, 3));
+
+        // rarities outside the set don't count either
+        catch(&mut userfile, FishRarity::Elusive);
+        catch(&mut userfile, FishRarity::Uncommon);
+        assert_eq!(rarities.progress(&userfile), (2, 3));
+        assert!(!rarities.is_complete(&userfile));
+
+        catch(&mut userfile, FishRarity::Rare);
+        assert!(rarities.is_complete(&userfile));
+    }
+}
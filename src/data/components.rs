@@ -0,0 +1,178 @@
+use std::fmt::Display;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::data::fish::Fish;
+use crate::data::money::Money;
+use crate::data::rods::BaseRod;
+use crate::data::shop::RodRarity;
+use crate::data::userfile::UserValues;
+
+/// base chance out of 1000 for a caught fish to drop a component, scaled by the fish's rarity
+const DROP_CHANCE: f32 = 30.0;
+/// the base rod id every crafted rod shares, they're built from components instead of rod data
+pub const CRAFTED_ROD_ID: &str = "crafted_rod";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ComponentKind {
+    Blank,
+    Reel,
+    Line,
+    Hook,
+}
+
+impl ComponentKind {
+    pub fn all() -> [Self; 4] {
+        [Self::Blank, Self::Reel, Self::Line, Self::Hook]
+    }
+}
+
+impl Display for ComponentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComponentKind::Blank => write!(f, "Blank"),
+            ComponentKind::Reel => write!(f, "Reel"),
+            ComponentKind::Line => write!(f, "Line"),
+            ComponentKind::Hook => write!(f, "Hook"),
+        }
+    }
+}
+
+/// A part used to craft a rod, a crafted rod's stats are the sum of its parts
+#[derive(Debug, Clone)]
+pub struct RodComponent {
+    pub name: String,
+    pub kind: ComponentKind,
+    pub catch_chance: f32,
+    pub catch_rate: f32,
+    pub depth: u32,
+    pub weight_limit: u32,
+    pub cost: Money,
+    pub rarity: RodRarity,
+}
+
+#[derive(Debug, Clone)]
+pub struct ComponentData {
+    pub components: Vec<RodComponent>,
+}
+
+impl ComponentData {
+    pub fn get_component_by_name(&self, name: &str) -> Option<&RodComponent> {
+        self.components.iter().find(|component| component.name == name)
+    }
+
+    pub fn generate_component(&self, rarity: RodRarity) -> RodComponent {
+        let mut rng = rand::thread_rng();
+
+        let mut components: Vec<&RodComponent> = self.components.iter().filter(|c| c.rarity.get_ident() == rarity.get_ident()).collect();
+        if components.is_empty() {
+            components = self.components.iter().collect();
+        }
+
+        components[rng.gen_range(0..components.len())].clone()
+    }
+
+    /// Rolls for a component dropped by a caught fish, rarer fish drop more often
+    pub fn roll_drop(&self, fish: &Fish) -> Option<RodComponent> {
+        let mut rng = rand::thread_rng();
+
+        if rng.gen_range(0..1000) as f32 >= DROP_CHANCE * fish.rarity.value_multiplier() {
+            return None;
+        }
+
+        // better fish drop better parts
+        let rarity = match fish.rarity.ident() {
+            0..=1 => RodRarity::Common,
+            2 => RodRarity::Uncommon,
+            3 => RodRarity::Rare,
+            _ => RodRarity::Epic,
+        };
+
+        Some(self.generate_component(rarity))
+    }
+}
+
+/// The parts a crafted rod was built from
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CraftedRod {
+    pub blank: String,
+    pub reel: String,
+    pub line: String,
+    pub hook: String,
+}
+
+impl CraftedRod {
+    pub fn parts(&self) -> [&String; 4] {
+        [&self.blank, &self.reel, &self.line, &self.hook]
+    }
+
+    /// Builds the rod's stats from its components
+    pub fn to_base(&self, component_data: &ComponentData) -> Option<BaseRod> {
+        let blank = component_data.get_component_by_name(&self.blank)?;
+
+        let mut base = BaseRod {
+            id: CRAFTED_ROD_ID.to_string(),
+            name: format!("Crafted {} Rod", blank.name.trim_end_matches(" Blank")),
+            description: format!("Hand-made with a {}, {}, {} and {}.", self.blank, self.reel, self.line, self.hook),
+            catch_chance: 0.0,
+            catch_rate: 0.0,
+            depth: 0,
+            weight_limit: 0,
+            cost: Money::ZERO,
+            rarity: blank.rarity.clone(),
+            prestige: 0,
+        };
+
+        for part in self.parts() {
+            let component = component_data.get_component_by_name(part)?;
+            base.catch_chance += component.catch_chance;
+            base.catch_rate += component.catch_rate;
+            base.depth += component.depth;
+            base.weight_limit += component.weight_limit;
+            base.cost += component.cost;
+        }
+
+        Some(base)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CraftError {
+    MissingComponent(String),
+    WrongKind(String),
+}
+
+impl Display for CraftError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CraftError::MissingComponent(name) => write!(f, "You don't have a {}!", name),
+            CraftError::WrongKind(name) => write!(f, "{} doesn't fit in that slot!", name),
+        }
+    }
+}
+
+/// Consumes the components from the player's inventory and equips the crafted rod
+pub fn craft_rod(userfile: &mut UserValues, crafted: CraftedRod, component_data: &ComponentData) -> Result<BaseRod, CraftError> {
+    for (part, kind) in crafted.parts().into_iter().zip(ComponentKind::all()) {
+        let component = component_data.get_component_by_name(part).ok_or(CraftError::MissingComponent(part.clone()))?;
+        if component.kind != kind {
+            return Err(CraftError::WrongKind(part.clone()));
+        }
+        if userfile.components.get(part).copied().unwrap_or(0) == 0 {
+            return Err(CraftError::MissingComponent(part.clone()));
+        }
+    }
+
+    for part in crafted.parts() {
+        if let Some(count) = userfile.components.get_mut(part) {
+            *count -= 1;
+        }
+    }
+    userfile.components.retain(|_, count| *count > 0);
+
+    let base = crafted.to_base(component_data).unwrap();
+
+    userfile.crafted_rod = Some(crafted);
+    userfile.rod_modifiers.clear();
+
+    Ok(base)
+}
@@ -0,0 +1,94 @@
+use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
+use crate::data::fish::FishRarity;
+
+/// A day of the year, events come back around on the same days every year
+#[derive(Debug, Clone, Copy)]
+pub struct CalendarDay {
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarDay {
+    fn in_year(&self, year: i32) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FishingEvent {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub start: CalendarDay,
+    /// the last day of the event, may be in the following year
+    pub end: CalendarDay,
+    /// rarity -> how many times more often it's rolled
+    pub rarity_boosts: Vec<(FishRarity, f32)>,
+    /// species id -> how many times more often it's picked
+    pub species_boosts: Vec<(String, f32)>,
+    /// earned for every fish caught while the event is running
+    pub currency: String,
+    /// rod id -> price in the event's currency
+    pub shop: Vec<(String, u32)>,
+}
+
+impl FishingEvent {
+    /// When the current run of the event is over, `None` if it isn't running
+    pub fn ends_at(&self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let today = now.date();
+
+        // a run that wraps over new year may have started last year
+        for year in [today.year() - 1, today.year()] {
+            let (Some(start), Some(end)) = (self.start.in_year(year), self.end.in_year(year)) else {
+                continue;
+            };
+            let end = if end < start {
+                let Some(end) = self.end.in_year(year + 1) else { continue };
+                end
+            } else {
+                end
+            };
+
+            if start <= today && today <= end {
+                return end.succ_opt()?.and_hms_opt(0, 0, 0);
+            }
+        }
+
+        None
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.ends_at(Local::now().naive_local()).is_some()
+    }
+
+    pub fn get_time_remaining(&self) -> String {
+        let now = Local::now().naive_local();
+        let Some(end) = self.ends_at(now) else {
+            return "Over".to_string();
+        };
+
+        let duration = end - now;
+
+        let days = duration.num_days();
+        let hours = duration.num_hours() - (days * 24);
+        let minutes = duration.num_minutes() - (duration.num_hours() * 60);
+
+        format!("{} days, {} hours, {} minutes", days, hours, minutes)
+    }
+
+    pub fn get_price(&self, rod: &str) -> Option<u32> {
+        self.shop.iter().find(|(name, _)| name == rod).map(|(_, price)| *price)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EventData {
+    pub events: Vec<FishingEvent>,
+}
+
+impl EventData {
+    /// The events running right now
+    pub fn active(&self) -> Vec<FishingEvent> {
+        self.events.iter().filter(|e| e.is_active()).cloned().collect()
+    }
+}
@@ -1,9 +1,15 @@
 use std::fmt::Display;
 use std::str::FromStr;
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::data::catalog::{Catalog, Identified};
+use crate::data::events::FishingEvent;
+use crate::data::locale::localized;
+use crate::data::money::Money;
 use crate::data::rods::Rod;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum FishRarity {
     Common, // 40%
     Uncommon, // 30%
@@ -36,6 +42,18 @@ impl FishRarity {
         }
     }
 
+    /// How far this rarity pulls weights towards record sizes, from 0 (not at all) to 1
+    pub fn record_shift(&self) -> f32 {
+        match self {
+            Self::Common => 0.0,
+            Self::Uncommon => 0.05,
+            Self::Rare => 0.1,
+            Self::Elusive => 0.2,
+            Self::Legendary => 0.35,
+            Self::Mythical => 0.5,
+        }
+    }
+
     pub fn value_multiplier(&self) -> f32 {
         match self {
             Self::Common => 1.0,
@@ -47,44 +65,23 @@ impl FishRarity {
         }
     }
 
-    pub fn weighted_random() -> Self {
-        let mut rng = rand::thread_rng();
-
-        let num = rng.gen_range(1..=1000);
-
-        let mut current_weight = 0;
-
-        // hey!("Mythical chance: {} generated: {}",
-        //     1000 - (Self::Common.get_weight() + Self::Uncommon.get_weight() + Self::Rare.get_weight() + Self::Elusive.get_weight() + Self::Legendary.get_weight()),
-        // num);
-
-        if num <= current_weight + Self::Common.get_weight() {
-            return Self::Common;
-        }
-        current_weight += Self::Common.get_weight();
-
-        if num <= current_weight + Self::Uncommon.get_weight() {
-            return Self::Uncommon;
-        }
-        current_weight += Self::Uncommon.get_weight();
-
-        if num <= current_weight + Self::Rare.get_weight() {
-            return Self::Rare;
-        }
-        current_weight += Self::Rare.get_weight();
+    pub fn all() -> [Self; 6] {
+        [Self::Common, Self::Uncommon, Self::Rare, Self::Elusive, Self::Legendary, Self::Mythical]
+    }
 
-        if num <= current_weight + Self::Elusive.get_weight() {
-            return Self::Elusive;
-        }
-        current_weight += Self::Elusive.get_weight();
+    /// Rolls a rarity by weight, `boost` multiplies each rarity's weight
+    pub fn weighted_random(boost: impl Fn(&Self) -> f32) -> Self {
+        let mut rng = rand::thread_rng();
 
-        if num <= current_weight + Self::Legendary.get_weight() {
-            return Self::Legendary;
-        }
-        current_weight += Self::Legendary.get_weight();
+        let weights: Vec<f32> = Self::all().iter().map(|r| r.get_weight() as f32 * boost(r)).collect();
+        let total: f32 = weights.iter().sum();
+        let mut num = rng.gen_range(0.0..total);
 
-        if num <= current_weight + Self::Mythical.get_weight() {
-            return Self::Mythical;
+        for (rarity, weight) in Self::all().into_iter().zip(weights) {
+            if num < weight {
+                return rarity;
+            }
+            num -= weight;
         }
         Self::Common
     }
@@ -119,20 +116,68 @@ impl Display for FishRarity {
     }
 }
 
+/// How a species' weights are spread between its min and max
+#[derive(Debug, Clone)]
+pub enum WeightDistribution {
+    /// every weight in the range is as likely, ignores `avg_weight`
+    Uniform,
+    /// weights bunch up around `avg_weight`, tighter the higher the value.
+    /// Common fish average out at exactly `avg_weight`
+    Peaked(f32),
+}
+
 #[derive(Debug, Clone)]
 pub struct FishType {
+    pub id: String,
     pub name: String,
     pub depth: u32,
     pub value: u32,
     pub min_weight: u32,
     pub avg_weight: u32,
     pub max_weight: u32,
+    pub weight_distribution: WeightDistribution,
+    /// how often this species is picked compared to others that can bite
+    pub spawn_weight: f32,
     pub min_rarity: FishRarity,
+    /// minimum prestige level for this fish to appear
+    pub prestige: u32,
+    /// id of the only location this fish can be found at, `None` for everywhere
+    pub location: Option<String>,
+    /// the event this fish only shows up during, `None` for all year
+    pub event: Option<String>,
 }
 
 impl FishType {
-    pub fn random_weight(&self) -> f32 {
-        rand::thread_rng().gen_range(self.min_weight as f32..self.max_weight as f32)
+    /// Whether this fish can be caught without an event or a prestige, the bestiary is complete once every one of these is seen
+    pub fn is_regular(&self) -> bool {
+        self.prestige == 0 && self.event.is_none()
+    }
+
+    /// Rolls a weight for this species, rarer fish lean towards the heavy end
+    pub fn random_weight(&self, rarity: &FishRarity) -> f32 {
+        let mut rng = rand::thread_rng();
+        let (min, avg, max) = (self.min_weight as f32, self.avg_weight as f32, self.max_weight as f32);
+        if max <= min {
+            return min;
+        }
+
+        match self.weight_distribution {
+            WeightDistribution::Uniform => min + (max - min) * rng.gen_range(0.0f32..1.0).powf(1.0 - rarity.record_shift()),
+            WeightDistribution::Peaked(concentration) => {
+                // picking the heavy side this often puts the mean right on the average
+                let heavy_chance = (avg - min) / (max - min);
+                let heavy_chance = heavy_chance + (1.0 - heavy_chance) * rarity.record_shift();
+
+                // how close to the average the weight lands on its side
+                let pull = rng.gen_range(0.0f32..1.0).powf(1.0 / concentration);
+
+                if rng.gen_range(0.0f32..1.0) < heavy_chance {
+                    max - (max - avg) * pull
+                } else {
+                    min + (avg - min) * pull
+                }
+            }
+        }
     }
 
     pub fn get_value(&self, weight: f32, fish_data: &FishData) -> f32 {
@@ -146,23 +191,107 @@ impl FishType {
 
 impl Display for FishType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+        write!(f, "{}", localized(&self.id, &self.name))
+    }
+}
+
+impl Identified for FishType {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct FishData {
     pub weight_factor: f32,
-    pub fish: Vec<FishType>,
+    pub fish: Catalog<FishType>,
+    /// the events running when this data was loaded
+    pub events: Vec<FishingEvent>,
 }
 
 impl FishData {
-    pub fn get_fish_above_depth(&self, depth: u32) -> Vec<&FishType> {
-        self.fish.iter().filter(|fish| fish.depth <= depth).collect()
+    /// The display name of a species id, or the id itself if the species no longer exists
+    pub fn species_name(&self, id: &str) -> String {
+        self.fish.get(id).map_or(id.to_string(), |fish| fish.to_string())
+    }
+
+    pub fn regular_fish(&self) -> impl Iterator<Item = &FishType> {
+        self.fish.iter().filter(|fish| fish.is_regular())
+    }
+
+    /// How many of the regular fish are in `seen`
+    pub fn regular_seen(&self, seen: &[String]) -> usize {
+        self.regular_fish().filter(|fish| seen.contains(&fish.id)).count()
+    }
+
+    /// Fish that can be reached at a depth while fishing at a location
+    pub fn get_fish_above_depth(&self, depth: u32, location: &str) -> Vec<&FishType> {
+        self.fish.iter()
+            .filter(|fish| fish.depth <= depth)
+            .filter(|fish| fish.location.as_ref().is_none_or(|l| l == location))
+            .filter(|fish| fish.event.as_ref().is_none_or(|e| self.events.iter().any(|event| &event.id == e)))
+            .collect()
     }
 
-    pub fn get_fish_by_name(&self, name: &str) -> Option<&FishType> {
-        self.fish.iter().find(|fish| fish.name == name)
+    /// How much more often a rarity is rolled during the running events
+    pub fn rarity_multiplier(&self, rarity: &FishRarity) -> f32 {
+        self.events.iter()
+            .flat_map(|e| &e.rarity_boosts)
+            .filter(|(r, _)| r.ident() == rarity.ident())
+            .map(|(_, multiplier)| multiplier)
+            .product()
+    }
+
+    /// How much more often a species is picked during the running events
+    pub fn species_multiplier(&self, species: &str) -> f32 {
+        self.events.iter()
+            .flat_map(|e| &e.species_boosts)
+            .filter(|(id, _)| id == species)
+            .map(|(_, multiplier)| multiplier)
+            .product()
+    }
+
+    /// Every species that can bite for this depth, location, prestige level and rarity
+    pub fn eligible_fish(&self, depth: u32, location: &str, prestige: u32, rarity: &FishRarity) -> Vec<&FishType> {
+        self.get_fish_above_depth(depth, location).into_iter()
+            .filter(|fish| fish.prestige <= prestige)
+            .filter(|fish| fish.min_rarity.ident() <= rarity.ident())
+            .collect()
+    }
+
+    /// Picks one of the eligible species by its spawn weight
+    pub fn pick_species(&self, depth: u32, location: &str, prestige: u32, rarity: &FishRarity) -> Result<&FishType, SelectError> {
+        let eligible = self.eligible_fish(depth, location, prestige, rarity);
+        if eligible.is_empty() {
+            return Err(SelectError::NothingInReach);
+        }
+
+        let weights = eligible.iter().map(|fish| fish.spawn_weight * self.species_multiplier(&fish.id));
+        let picker = WeightedIndex::new(weights).map_err(|_| SelectError::NothingInReach)?;
+
+        Ok(eligible[picker.sample(&mut rand::thread_rng())])
+    }
+
+    pub fn get_fish(&self, id: &str) -> Option<&FishType> {
+        self.fish.get(id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum SelectError {
+    /// no species can bite with the rod at this location
+    NothingInReach,
+}
+
+impl Display for SelectError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SelectError::NothingInReach => write!(f, "There are no fish within reach of your rod here!"),
+        }
     }
 }
 
@@ -174,35 +303,26 @@ pub struct Fish {
 }
 
 impl Fish {
-    pub fn random_fish(fish_data: &FishData, caught_with: &Rod) -> Self {
-        let mut rng = rand::thread_rng();
-
+    pub fn random_fish(fish_data: &FishData, caught_with: &Rod) -> Result<Self, SelectError> {
         // generate the fish's rarity
-        let rarity = FishRarity::weighted_random();
-
-        // generate the fish type based on depth and randomness
-        let fishing_depth = caught_with.get_depth();
-        let fish_above_depth = fish_data.get_fish_above_depth(fishing_depth);
+        let rarity = FishRarity::weighted_random(|r| fish_data.rarity_multiplier(r));
 
-        let mut fish_type = fish_above_depth[rng.gen_range(0..fish_above_depth.len())].clone();
-
-        // ensure rare fish only pop up when the rarity is high enough
-        while fish_type.min_rarity.clone().ident() > rarity.ident() {
-            fish_type = fish_above_depth[rng.gen_range(0..fish_above_depth.len())].clone();
-        }
+        // pick from the species that can bite at this depth and rarity
+        let fish_type = fish_data.pick_species(
+            caught_with.get_depth(), &caught_with.location.id, caught_with.bonuses.prestige, &rarity)?.clone();
 
         // generate the fish's weight
-        let weight = (fish_type.random_weight() * 10.0).round() / 10.0;
+        let weight = (fish_type.random_weight(&rarity) * 10.0).round() / 10.0;
 
-        Self {
+        Ok(Self {
             fish_type,
-            rarity: rarity.clone(),
+            rarity,
             weight,
-        }
+        })
     }
 
-    pub fn get_value(&self, fish_data: &FishData) -> u32 {
-        (self.fish_type.get_value(self.weight, fish_data) * self.rarity.value_multiplier()) as u32
+    pub fn get_value(&self, fish_data: &FishData) -> Money {
+        Money::from_f32(self.fish_type.get_value(self.weight, fish_data) * self.rarity.value_multiplier())
     }
 }
 
@@ -210,4 +330,163 @@ impl Display for Fish {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} {}", self.rarity, self.fish_type)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 200_000;
+
+    fn species(min_weight: u32, avg_weight: u32, max_weight: u32) -> FishType {
+        FishType {
+            id: "test_fish".to_string(),
+            name: "Test Fish".to_string(),
+            depth: 0,
+            value: 10,
+            min_weight,
+            avg_weight,
+            max_weight,
+            weight_distribution: WeightDistribution::Peaked(2.0),
+            spawn_weight: 1.0,
+            min_rarity: FishRarity::Common,
+            prestige: 0,
+            location: None,
+            event: None,
+        }
+    }
+
+    fn fish_data(fish: Vec<FishType>) -> FishData {
+        FishData {
+            weight_factor: 0.8,
+            fish: Catalog::new(fish),
+            events: Vec::new(),
+        }
+    }
+
+    /// The selection used before `pick_species`: any reachable species, re-rolled until the rarity allows it
+    fn pick_species_rerolling<'a>(fish_data: &'a FishData, depth: u32, location: &str, prestige: u32, rarity: &FishRarity) -> &'a FishType {
+        let mut rng = rand::thread_rng();
+        let reachable: Vec<&FishType> = fish_data.get_fish_above_depth(depth, location).into_iter()
+            .filter(|fish| fish.prestige <= prestige)
+            .collect();
+
+        let mut fish = reachable[rng.gen_range(0..reachable.len())];
+        while fish.min_rarity.ident() > rarity.ident() {
+            fish = reachable[rng.gen_range(0..reachable.len())];
+        }
+        fish
+    }
+
+    fn mean_weight(fish: &FishType, rarity: &FishRarity) -> f32 {
+        (0..SAMPLES).map(|_| fish.random_weight(rarity)).sum::<f32>() / SAMPLES as f32
+    }
+
+    #[test]
+    fn peaked_mean_matches_average() {
+        for (min, avg, max) in [(15, 20, 25), (1, 13, 110), (200, 210, 400), (200, 1000, 2200)] {
+            let fish = species(min, avg, max);
+            let mean = mean_weight(&fish, &FishRarity::Common);
+            let tolerance = (max - min) as f32 * 0.01;
+            assert!((mean - avg as f32).abs() < tolerance, "{}-{}-{}: mean was {}", min, avg, max, mean);
+        }
+    }
+
+    #[test]
+    fn species_data_mean_matches_average() {
+        for fish in crate::data::fish_data().fish.iter() {
+            if !matches!(fish.weight_distribution, WeightDistribution::Peaked(_)) {
+                continue;
+            }
+            let mean = mean_weight(fish, &FishRarity::Common);
+            let tolerance = (fish.max_weight - fish.min_weight) as f32 * 0.01;
+            assert!((mean - fish.avg_weight as f32).abs() < tolerance, "{}: mean was {}", fish.name, mean);
+        }
+    }
+
+    #[test]
+    fn weights_stay_in_range() {
+        let fish = species(1, 13, 110);
+        for rarity in FishRarity::all() {
+            for _ in 0..10_000 {
+                let weight = fish.random_weight(&rarity);
+                assert!((1.0..=110.0).contains(&weight), "{} out of range", weight);
+            }
+        }
+    }
+
+    #[test]
+    fn rarer_fish_are_heavier() {
+        let fish = species(20, 60, 200);
+        let common = mean_weight(&fish, &FishRarity::Common);
+        let mythical = mean_weight(&fish, &FishRarity::Mythical);
+        assert!(mythical > common, "mythical {} <= common {}", mythical, common);
+    }
+
+    #[test]
+    fn pick_species_errors_when_nothing_in_reach() {
+        let fish_data = crate::data::fish_data();
+        assert!(fish_data.pick_species(0, "lakeshore", 0, &FishRarity::Mythical).is_err());
+    }
+
+    #[test]
+    fn pick_species_respects_min_rarity() {
+        let fish_data = crate::data::fish_data();
+        for rarity in FishRarity::all() {
+            for _ in 0..1000 {
+                let fish = fish_data.pick_species(1000, "lakeshore", 0, &rarity).unwrap();
+                assert!(fish.min_rarity.ident() <= rarity.ident(), "{} picked at {}", fish.name, rarity);
+            }
+        }
+    }
+
+    #[test]
+    fn pick_species_follows_spawn_weights() {
+        let mut heavy = species(1, 2, 3);
+        heavy.id = "heavy".to_string();
+        heavy.spawn_weight = 3.0;
+        let fish_data = fish_data(vec![species(1, 2, 3), heavy]);
+
+        let picks = (0..SAMPLES)
+            .filter(|_| fish_data.pick_species(10, "lakeshore", 0, &FishRarity::Common).unwrap().id == "heavy")
+            .count();
+        let share = picks as f32 / SAMPLES as f32;
+        assert!((share - 0.75).abs() < 0.01, "heavy share was {}", share);
+    }
+
+    #[test]
+    #[ignore = "benchmark, run with `cargo test --release -- --ignored --nocapture`"]
+    fn bench_species_selection() {
+        let fish_data = crate::data::fish_data();
+
+        for rarity in FishRarity::all() {
+            let start = std::time::Instant::now();
+            for _ in 0..SAMPLES {
+                pick_species_rerolling(&fish_data, 1000, "lakeshore", 0, &rarity);
+            }
+            let rerolling = start.elapsed();
+
+            let start = std::time::Instant::now();
+            for _ in 0..SAMPLES {
+                fish_data.pick_species(1000, "lakeshore", 0, &rarity).unwrap();
+            }
+            let eligible = start.elapsed();
+
+            println!("{:>9}: re-rolling {:?}, eligible set {:?} ({} picks)", rarity.to_string(), rerolling, eligible, SAMPLES);
+        }
+    }
+
+    #[test]
+    fn bestiary_counts_regular_fish() {
+        let fish_data = fish_data(vec![
+            FishType { id: "regular".to_string(), ..species(1, 2, 3) },
+            FishType { id: "prestige".to_string(), prestige: 1, ..species(1, 2, 3) },
+            FishType { id: "event".to_string(), event: Some("summer_festival".to_string()), ..species(1, 2, 3) },
+            FishType { id: "coastal".to_string(), location: Some("coastal_waters".to_string()), ..species(1, 2, 3) },
+        ]);
+        let seen = ["regular".to_string(), "prestige".to_string(), "event".to_string()];
+
+        assert_eq!(fish_data.regular_fish().map(|f| f.id.as_str()).collect::<Vec<_>>(), ["regular", "coastal"]);
+        assert_eq!(fish_data.regular_seen(&seen), 1);
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt::Display;
+use std::io::Write;
+use std::path::Path;
+use chrono::{DateTime, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use crate::data::fish::{Fish, FishRarity};
+use crate::data::money::Money;
+use crate::data::rods::Rod;
+use crate::nay;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum CatchOutcome {
+    Caught,
+    Escaped,
+    LineBroke,
+    /// taken by a random event
+    Stolen,
+}
+
+impl CatchOutcome {
+    pub fn all() -> [Self; 4] {
+        [Self::Caught, Self::Escaped, Self::LineBroke, Self::Stolen]
+    }
+}
+
+impl Display for CatchOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatchOutcome::Caught => write!(f, "Caught"),
+            CatchOutcome::Escaped => write!(f, "Escaped"),
+            CatchOutcome::LineBroke => write!(f, "Line Broke"),
+            CatchOutcome::Stolen => write!(f, "Stolen"),
+        }
+    }
+}
+
+/// The result of a single cast
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CatchRecord {
+    /// species id
+    pub species: String,
+    pub rarity: FishRarity,
+    pub weight: f32,
+    pub value: Money,
+    /// base rod id, without its modifiers
+    pub rod: String,
+    pub outcome: CatchOutcome,
+    pub timestamp: i64,
+    /// how long the cast took in seconds
+    pub cast_duration: i64,
+}
+
+impl CatchRecord {
+    pub fn new(fish: &Fish, value: Money, rod: &Rod, outcome: CatchOutcome, cast_duration: i64, timestamp: i64) -> Self {
+        Self {
+            species: fish.fish_type.id.clone(),
+            rarity: fish.rarity.clone(),
+            weight: fish.weight,
+            value,
+            rod: rod.base.id.clone(),
+            outcome,
+            timestamp,
+            cast_duration,
+        }
+    }
+}
+
+pub fn get_history_path() -> String {
+    "./data/history.jsonl".to_string()
+}
+
+/// Appends a cast to the history log, one json object per line
+pub fn append_history(record: &CatchRecord) {
+    append_histories(std::slice::from_ref(record));
+}
+
+/// Appends several casts to the history log in a single write
+pub fn append_histories(records: &[CatchRecord]) {
+    if records.is_empty() {
+        return;
+    }
+
+    let raw_path = get_history_path();
+    let path = Path::new(raw_path.as_str());
+
+    if let Err(e) = std::fs::create_dir_all(path.parent().unwrap()) {
+        nay!("Failed to create history directories: {}", e);
+        return;
+    }
+
+    let file = std::fs::OpenOptions::new().create(true).append(true).open(path);
+    let mut file = match file {
+        Ok(f) => f,
+        Err(e) => {
+            nay!("Failed to open history: {}", e);
+            return;
+        }
+    };
+
+    let mut lines = String::new();
+    for record in records {
+        lines.push_str(&serde_json::to_string(record).unwrap());
+        lines.push('\n');
+    }
+    if let Err(e) = file.write_all(lines.as_bytes()) {
+        nay!("Failed to write history: {}", e);
+    }
+}
+
+/// The size of the history file, used to tell when it needs reloading
+pub fn history_len() -> u64 {
+    std::fs::metadata(get_history_path()).map(|m| m.len()).unwrap_or(0)
+}
+
+pub fn read_history() -> Vec<CatchRecord> {
+    let Ok(contents) = std::fs::read_to_string(get_history_path()) else {
+        return Vec::new();
+    };
+
+    contents.lines()
+        .filter_map(|line| serde_json::from_str::<CatchRecord>(line).ok())
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HistoryRange {
+    Day,
+    Week,
+    Month,
+    AllTime,
+    /// local calendar days from `from` to `to`, both included
+    Dates { from: NaiveDate, to: NaiveDate },
+}
+
+impl HistoryRange {
+    /// the ranges relative to now
+    pub fn all() -> [Self; 4] {
+        [Self::Day, Self::Week, Self::Month, Self::AllTime]
+    }
+
+    pub fn contains(&self, timestamp: i64) -> bool {
+        let now = Local::now().timestamp();
+        match self {
+            HistoryRange::Day => timestamp >= now - 60 * 60 * 24,
+            HistoryRange::Week => timestamp >= now - 60 * 60 * 24 * 7,
+            HistoryRange::Month => timestamp >= now - 60 * 60 * 24 * 30,
+            HistoryRange::AllTime => true,
+            HistoryRange::Dates { from, to } => DateTime::from_timestamp(timestamp, 0)
+                .map(|time| time.with_timezone(&Local).date_naive())
+                .is_some_and(|date| date >= *from.min(to) && date <= *from.max(to)),
+        }
+    }
+}
+
+impl Display for HistoryRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryRange::Day => write!(f, "Last 24 hours"),
+            HistoryRange::Week => write!(f, "Last 7 days"),
+            HistoryRange::Month => write!(f, "Last 30 days"),
+            HistoryRange::AllTime => write!(f, "All time"),
+            HistoryRange::Dates { from, to } => write!(f, "{} to {}", from, to),
+        }
+    }
+}
+
+/// Filters for the history panel, `None` matches everything
+#[derive(Debug, Clone)]
+pub struct HistoryFilter {
+    pub species: Option<String>,
+    pub rarity: Option<FishRarity>,
+    pub outcome: Option<CatchOutcome>,
+    pub range: HistoryRange,
+}
+
+impl Default for HistoryFilter {
+    fn default() -> Self {
+        Self {
+            species: None,
+            rarity: None,
+            outcome: None,
+            range: HistoryRange::AllTime,
+        }
+    }
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, record: &CatchRecord) -> bool {
+        self.species.as_ref().is_none_or(|s| &record.species == s)
+            && self.rarity.as_ref().is_none_or(|r| record.rarity.ident() == r.ident())
+            && self.outcome.is_none_or(|o| record.outcome == o)
+            && self.range.contains(record.timestamp)
+    }
+
+    pub fn apply<'a>(&self, history: &'a [CatchRecord]) -> Vec<&'a CatchRecord> {
+        history.iter().filter(|r| self.matches(r)).collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn to_csv(records: &[&CatchRecord]) -> String {
+    let mut csv = String::from("timestamp,species,rarity,weight,value,rod,outcome,cast_duration\n");
+    for record in records {
+        csv.push_str(&format!("{},{},{},{},{},{},{},{}\n",
+            record.timestamp, csv_field(&record.species), record.rarity, record.weight,
+            record.value.dollars(), csv_field(&record.rod), record.outcome, record.cast_duration));
+    }
+    csv
+}
+
+/// Writes the records to ./data/exports and returns the path written to
+pub fn export_history(records: &[&CatchRecord], format: ExportFormat) -> std::io::Result<String> {
+    let extension = match format {
+        ExportFormat::Csv => "csv",
+        ExportFormat::Json => "json",
+    };
+    let raw_path = format!("./data/exports/history_{}.{}", Local::now().format("%Y%m%d_%H%M%S"), extension);
+    let path = Path::new(raw_path.as_str());
+
+    std::fs::create_dir_all(path.parent().unwrap())?;
+
+    let contents = match format {
+        ExportFormat::Csv => to_csv(records),
+        ExportFormat::Json => serde_json::to_string_pretty(records)?,
+    };
+
+    std::fs::write(path, contents)?;
+
+    Ok(raw_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+    use super::*;
+
+    fn record(species: &str, rarity: FishRarity, outcome: CatchOutcome, timestamp: i64) -> CatchRecord {
+        CatchRecord {
+            species: species.to_string(),
+            rarity,
+            weight: 2.5,
+            value: Money::new(40),
+            rod: "stick_with_string".to_string(),
+            outcome,
+            timestamp,
+            cast_duration: 12,
+        }
+    }
+
+    fn noon(date: NaiveDate) -> i64 {
+        Local.from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap()).unwrap().timestamp()
+    }
+
+    #[test]
+    fn filters_combine() {
+        let now = Local::now().timestamp();
+        let history = [
+            record("bass", FishRarity::Common, CatchOutcome::Caught, now),
+            record("bass", FishRarity::Rare, CatchOutcome::Caught, now),
+            record("bass", FishRarity::Rare, CatchOutcome::Escaped, now),
+            record("pike", FishRarity::Rare, CatchOutcome::Caught, now),
+            record("bass", FishRarity::Rare, CatchOutcome::Caught, now - 60 * 60 * 48),
+        ];
+
+        let filter = HistoryFilter {
+            species: Some("bass".to_string()),
+            rarity: Some(FishRarity::Rare),
+            outcome: Some(CatchOutcome::Caught),
+            range: HistoryRange::Day,
+        };
+        assert_eq!(filter.apply(&history).len(), 1);
+        assert_eq!(HistoryFilter::default().apply(&history).len(), 5);
+    }
+
+    #[test]
+    fn date_ranges_include_both_ends() {
+        let from = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap();
+        let range = HistoryRange::Dates { from, to };
+
+        assert!(range.contains(noon(from)));
+        assert!(range.contains(noon(to)));
+        assert!(!range.contains(noon(from - Duration::days(1))));
+        assert!(!range.contains(noon(to + Duration::days(1))));
+        // picking the dates backwards still works
+        assert!(HistoryRange::Dates { from: to, to: from }.contains(noon(from + Duration::days(1))));
+    }
+
+    #[test]
+    fn csv_fields_are_escaped() {
+        let mut tricky = record("bass", FishRarity::Common, CatchOutcome::Caught, 0);
+        tricky.rod = "Rod, \"Lucky\"\nEdition".to_string();
+        let csv = to_csv(&[&tricky]);
+
+        assert_eq!(csv.lines().next().unwrap(), "timestamp,species,rarity,weight,value,rod,outcome,cast_duration");
+        assert!(csv.contains(",\"Rod, \"\"Lucky\"\"\nEdition\",Caught,"), "{}", csv);
+        assert!(csv.starts_with("timestamp") && csv.contains("\n0,bass,Common,2.5,40,"));
+    }
+
+    #[test]
+    fn records_store_the_base_rod() {
+        let rod_data = crate::data::rod_data();
+        let userfile = crate::data::userfile::UserValues {
+            rod_modifiers: vec!["sharp".to_string()],
+            ..Default::default()
+        };
+        let rod = userfile.get_rod(&rod_data, &crate::data::component_data(), &crate::data::tackle_data(), &crate::data::boat_data(), &crate::data::skill_data());
+        let fish = Fish {
+            fish_type: crate::data::fish_data().fish[0].clone(),
+            rarity: FishRarity::Common,
+            weight: 2.5,
+        };
+
+        let record = CatchRecord::new(&fish, Money::new(40), &rod, CatchOutcome::Caught, 12, 0);
+        assert_eq!(record.rod, "stick_with_string");
+        assert_eq!(rod_data.rod_name(&record.rod), "Stick with String");
+    }
+}
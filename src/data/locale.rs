@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+pub fn get_locale_path() -> String {
+    "./data/locale.json".to_string()
+}
+
+/// id -> display name overrides, read once from the locale file if there is one
+fn overrides() -> &'static HashMap<String, String> {
+    static OVERRIDES: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+    OVERRIDES.get_or_init(|| {
+        std::fs::read_to_string(get_locale_path()).ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    })
+}
+
+/// The display name for an id, falling back to the name it's defined with
+pub fn localized<'a>(id: &str, default: &'a str) -> &'a str {
+    overrides().get(id).map(String::as_str).unwrap_or(default)
+}
@@ -0,0 +1,189 @@
+use std::fmt::Display;
+use rand::Rng;
+use crate::data::catalog::{Catalog, Identified};
+use crate::data::locale::localized;
+use crate::data::money::Money;
+use crate::data::quests::QuestReward;
+use crate::data::rods::RodData;
+use crate::data::userfile::UserValues;
+
+/// chance out of 1000 for a cast to reel in a loot item
+const LOOT_CHANCE: u32 = 150;
+
+#[derive(Debug, Clone)]
+pub enum LootKind {
+    /// trash that can be recycled for a little money
+    Junk,
+    /// opened for one of its contents, picked at random
+    Treasure(Vec<QuestReward>),
+}
+
+#[derive(Debug, Clone)]
+pub struct LootItem {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub kind: LootKind,
+    /// how often this item is picked from the drop table
+    pub weight: u32,
+    /// the rod has to reach this deep to snag the item
+    pub depth: u32,
+    /// money paid when the item is recycled
+    pub recycle_value: Money,
+}
+
+impl Display for LootItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", localized(&self.id, &self.name))
+    }
+}
+
+impl Identified for LootItem {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum LootError {
+    NoneOwned,
+    UnknownItem,
+    NotTreasure,
+}
+
+impl Display for LootError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LootError::NoneOwned => write!(f, "You don't have any of those!"),
+            LootError::UnknownItem => write!(f, "That item doesn't exist!"),
+            LootError::NotTreasure => write!(f, "There's nothing inside that!"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LootData {
+    pub items: Catalog<LootItem>,
+}
+
+impl LootData {
+    pub fn get_item(&self, id: &str) -> Option<&LootItem> {
+        self.items.get(id)
+    }
+
+    /// Rolls the drop table for a cast reaching `depth`
+    pub fn roll_drop(&self, depth: u32) -> Option<&LootItem> {
+        let mut rng = rand::thread_rng();
+
+        if rng.gen_range(0..1000) >= LOOT_CHANCE {
+            return None;
+        }
+
+        let items: Vec<&LootItem> = self.items.iter().filter(|item| item.depth <= depth).collect();
+        let total: u32 = items.iter().map(|item| item.weight).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut num = rng.gen_range(0..total);
+        for item in items {
+            if num < item.weight {
+                return Some(item);
+            }
+            num -= item.weight;
+        }
+        None
+    }
+}
+
+/// Adds a looted item to the player's inventory
+pub fn add_loot(userfile: &mut UserValues, item: &LootItem) {
+    *userfile.loot.entry(item.id.clone()).or_insert(0) += 1;
+    if !userfile.items_seen.contains(&item.id) {
+        userfile.items_seen.push(item.id.clone());
+    }
+}
+
+fn take_item(userfile: &mut UserValues, id: &str) -> Result<(), LootError> {
+    match userfile.loot.get_mut(id) {
+        Some(count) if *count > 0 => {
+            *count -= 1;
+            if *count == 0 {
+                userfile.loot.remove(id);
+            }
+            Ok(())
+        }
+        _ => Err(LootError::NoneOwned),
+    }
+}
+
+/// Recycles one of an item for its recycle value
+pub fn recycle(userfile: &mut UserValues, id: &str, loot_data: &LootData) -> Result<Money, LootError> {
+    let item = loot_data.get_item(id).ok_or(LootError::UnknownItem)?;
+    take_item(userfile, id)?;
+
+    userfile.money += item.recycle_value;
+    Ok(item.recycle_value)
+}
+
+/// Opens a treasure item, returning a message describing what was inside
+pub fn open_treasure(userfile: &mut UserValues, id: &str, loot_data: &LootData, rod_data: &RodData) -> Result<String, LootError> {
+    let item = loot_data.get_item(id).ok_or(LootError::UnknownItem)?;
+    let LootKind::Treasure(contents) = &item.kind else {
+        return Err(LootError::NotTreasure);
+    };
+    if contents.is_empty() {
+        return Err(LootError::NotTreasure);
+    }
+    take_item(userfile, id)?;
+
+    let reward = &contents[rand::thread_rng().gen_range(0..contents.len())];
+    Ok(userfile.claim_reward(reward, rod_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chest(contents: Vec<QuestReward>) -> LootData {
+        LootData {
+            items: Catalog::new(vec![LootItem {
+                id: "chest".to_string(),
+                name: "Chest".to_string(),
+                description: String::new(),
+                kind: LootKind::Treasure(contents),
+                weight: 1,
+                depth: 0,
+                recycle_value: Money::new(1),
+            }]),
+        }
+    }
+
+    #[test]
+    fn treasure_never_replaces_modifiers() {
+        let loot_data = chest(vec![QuestReward::Modifier("sharp".to_string())]);
+        let mut userfile = UserValues {
+            rod_modifiers: vec!["masterwork".to_string(), "upgraded".to_string(), "deep_diving".to_string()],
+            ..Default::default()
+        };
+        add_loot(&mut userfile, &loot_data.items[0]);
+
+        open_treasure(&mut userfile, "chest", &loot_data, &crate::data::rod_data()).unwrap();
+        assert_eq!(userfile.rod_modifiers, ["masterwork", "upgraded", "deep_diving"]);
+        assert_eq!(userfile.pending_modifiers, ["sharp"]);
+        assert!(userfile.loot.is_empty());
+    }
+
+    #[test]
+    fn treasure_has_to_be_owned() {
+        let loot_data = chest(vec![QuestReward::Money(Money::new(100))]);
+        let mut userfile = UserValues::default();
+
+        assert!(matches!(open_treasure(&mut userfile, "chest", &loot_data, &crate::data::rod_data()), Err(LootError::NoneOwned)));
+        assert_eq!(userfile.money, Money::ZERO);
+    }
+}
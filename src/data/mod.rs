@@ -1,522 +1,1950 @@
-use crate::data::fish::{FishData, FishRarity, FishType};
-use crate::data::rods::{BaseRod, RodData, RodModifier};
+use crate::data::achievements::{Achievement, AchievementCondition, AchievementData};
+use crate::data::boats::{Boat, BoatData, Location};
+use crate::data::catalog::Catalog;
+use crate::data::collections::{CollectionData, CollectionSet, SetRequirement};
+use crate::data::components::{ComponentData, ComponentKind, RodComponent};
+use crate::data::events::{CalendarDay, EventData, FishingEvent};
+use crate::data::fish::{FishData, FishRarity, FishType, WeightDistribution};
+use crate::data::loot::{LootData, LootItem, LootKind};
+use crate::data::money::Money;
+use crate::data::quests::QuestReward;
+use crate::data::random_events::{EventCondition, EventEffect, RandomEvent, RandomEventData};
+use crate::data::rods::{BaseRod, ModifierTier, RodData, RodModifier};
 use crate::data::shop::RodRarity;
+use crate::data::skills::{Skill, SkillData, SkillEffect};
+use crate::data::tackle::{Tackle, TackleData, TackleSlot};
+use crate::data::tournaments::{AiAngler, Tournament, TournamentData, TournamentKind};
 
+pub mod achievements;
+pub mod aquarium;
+pub mod boats;
+pub mod catalog;
+pub mod collections;
+pub mod components;
+pub mod events;
 pub mod fish;
+pub mod history;
+pub mod locale;
+pub mod loot;
+pub mod money;
+pub mod prestige;
+pub mod quests;
+pub mod random_events;
+pub mod records;
 pub mod rods;
 pub mod shop;
+pub mod skills;
+pub mod stat_pipeline;
+pub mod stats;
+pub mod tackle;
+pub mod tournaments;
 pub mod userfile;
 
 pub fn fish_data() -> FishData {
     FishData {
         weight_factor: 0.8,
-        fish: vec![
-            FishType {
-                name: "Old Boot".to_string(),
-                depth: 1,
-                value: 1,
-                min_weight: 1,
-                avg_weight: 1,
-                max_weight: 2,
-                min_rarity: FishRarity::Common,
-            },
-            FishType {
-                name: "Tin Can".to_string(),
-                depth: 1,
-                value: 1,
-                min_weight: 1,
-                avg_weight: 1,
-                max_weight: 2,
-                min_rarity: FishRarity::Common,
-            },
-            FishType {
-                name: "Seaweed".to_string(),
-                depth: 1,
-                value: 2,
-                min_weight: 1,
-                avg_weight: 1,
-                max_weight: 2,
-                min_rarity: FishRarity::Common,
-            },
-            FishType {
-                name: "Plastic Bag".to_string(),
-                depth: 1,
-                value: 1,
-                min_weight: 1,
-                avg_weight: 1,
-                max_weight: 2,
-                min_rarity: FishRarity::Common,
-            },
-            FishType {
-                name: "Plastic Bottle".to_string(),
-                depth: 1,
-                value: 1,
-                min_weight: 1,
-                avg_weight: 1,
-                max_weight: 2,
-                min_rarity: FishRarity::Common,
-            },
-            FishType {
-                name: "Tire".to_string(),
-                depth: 1,
-                value: 5,
-                min_weight: 1,
-                avg_weight: 1,
-                max_weight: 2,
-                min_rarity: FishRarity::Common,
-            },
+        fish: Catalog::new(vec![
             FishType {
+                id: "trout".to_string(),
                 name: "Trout".to_string(),
                 depth: 10,
                 value: 8,
                 min_weight: 15,
                 avg_weight: 20,
                 max_weight: 25,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "rainbow_trout".to_string(),
                 name: "Rainbow Trout".to_string(),
                 depth: 10,
                 value: 30,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 5,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Elusive,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "bass".to_string(),
                 name: "Bass".to_string(),
                 depth: 25,
                 value: 6,
                 min_weight: 5,
                 avg_weight: 12,
                 max_weight: 20,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "salmon".to_string(),
                 name: "Salmon".to_string(),
                 depth: 20,
                 value: 12,
                 min_weight: 5,
                 avg_weight: 10,
                 max_weight: 30,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "tuna".to_string(),
                 name: "Tuna".to_string(),
                 depth: 30,
                 value: 75,
                 min_weight: 40,
                 avg_weight: 300,
                 max_weight: 500,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Rare,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "marlin".to_string(),
                 name: "Marlin".to_string(),
                 depth: 45,
                 value: 30,
                 min_weight: 200,
                 avg_weight: 210,
                 max_weight: 400,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "perch".to_string(),
                 name: "Perch".to_string(),
                 depth: 8,
                 value: 5,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 4,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "catfish".to_string(),
                 name: "Catfish".to_string(),
                 depth: 25,
                 value: 30,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 4,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "swordfish".to_string(),
                 name: "Swordfish".to_string(),
                 depth: 40,
                 value: 80,
                 min_weight: 50,
                 avg_weight: 180,
                 max_weight: 500,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Rare,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "pike".to_string(),
                 name: "Pike".to_string(),
                 depth: 18,
                 value: 8,
                 min_weight: 20,
                 avg_weight: 28,
                 max_weight: 40,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "cod".to_string(),
                 name: "Cod".to_string(),
                 depth: 20,
                 value: 8,
                 min_weight: 6,
                 avg_weight: 9,
                 max_weight: 15,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "herring".to_string(),
                 name: "Herring".to_string(),
                 depth: 10,
                 value: 5,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 3,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.5,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "mackerel".to_string(),
                 name: "Mackerel".to_string(),
                 depth: 15,
                 value: 4,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 4,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "sardine".to_string(),
                 name: "Sardine".to_string(),
                 depth: 5,
                 value: 2,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 3,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.5,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "eel".to_string(),
                 name: "Eel".to_string(),
                 depth: 20,
                 value: 12,
                 min_weight: 10,
                 avg_weight: 15,
                 max_weight: 30,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "shark".to_string(),
                 name: "Shark".to_string(),
                 depth: 60,
                 value: 50,
                 min_weight: 200,
                 avg_weight: 300,
                 max_weight: 500,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Rare,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "tilapia".to_string(),
                 name: "Tilapia".to_string(),
                 depth: 10,
                 value: 5,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 3,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "carp".to_string(),
                 name: "Carp".to_string(),
                 depth: 10,
                 value: 5,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 3,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "guppy".to_string(),
                 name: "Guppy".to_string(),
                 depth: 5,
                 value: 2,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 3,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.5,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "jellyfish".to_string(),
                 name: "Jellyfish".to_string(),
                 depth: 5,
                 value: 12,
                 min_weight: 1,
                 avg_weight: 2,
                 max_weight: 3,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 0.5,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "dogfish".to_string(),
                 name: "Dogfish".to_string(),
                 depth: 15,
                 value: 15,
                 min_weight: 4,
                 avg_weight: 8,
                 max_weight: 21,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Rare,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "stingray".to_string(),
                 name: "Stingray".to_string(),
                 depth: 5,
                 value: 50,
                 min_weight: 31,
                 avg_weight: 40,
                 max_weight: 75,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Elusive,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "barramundi".to_string(),
                 name: "Barramundi".to_string(),
                 depth: 8,
                 value: 10,
                 min_weight: 1,
                 avg_weight: 13,
                 max_weight: 110,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "anglerfish".to_string(),
                 name: "Anglerfish".to_string(),
                 depth: 100,
                 value: 75,
                 min_weight: 60,
                 avg_weight: 70,
                 max_weight: 110,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "lanternfish".to_string(),
                 name: "Lanternfish".to_string(),
                 depth: 110,
                 value: 50,
                 min_weight: 1,
                 avg_weight: 1,
                 max_weight: 2,
+                weight_distribution: WeightDistribution::Uniform,
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "fangtooth".to_string(),
                 name: "Fangtooth".to_string(),
                 depth: 110,
                 value: 150,
                 min_weight: 60,
                 avg_weight: 70,
                 max_weight: 110,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Elusive,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "viperfish".to_string(),
                 name: "Viperfish".to_string(),
                 depth: 110,
                 value: 75,
                 min_weight: 1,
                 avg_weight: 1,
                 max_weight: 2,
+                weight_distribution: WeightDistribution::Uniform,
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "daggertooth".to_string(),
                 name: "Daggertooth".to_string(),
                 depth: 110,
                 value: 75,
                 min_weight: 1,
                 avg_weight: 4,
                 max_weight: 6,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "barracudina".to_string(),
                 name: "Barracudina".to_string(),
                 depth: 110,
                 value: 50,
                 min_weight: 0,
                 avg_weight: 1,
                 max_weight: 2,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "antarctic_toothfish".to_string(),
                 name: "Antarctic Toothfish".to_string(),
                 depth: 110,
                 value: 150,
                 min_weight: 50,
                 avg_weight: 97,
                 max_weight: 330,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Rare,
+                prestige: 0,
+                location: None,
+                event: None,
             },
             FishType {
+                id: "loch_ness_monster".to_string(),
                 name: "Loch Ness Monster".to_string(),
                 depth: 150,
                 value: 1000,
                 min_weight: 800,
                 avg_weight: 1000,
                 max_weight: 1200,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
                 min_rarity: FishRarity::Mythical,
+                prestige: 0,
+                location: None,
+                event: None,
             },
-        ],
+            // Prestige fish
+            FishType {
+                id: "golden_koi".to_string(),
+                name: "Golden Koi".to_string(),
+                depth: 5,
+                value: 300,
+                min_weight: 5,
+                avg_weight: 12,
+                max_weight: 25,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 0.5,
+                min_rarity: FishRarity::Rare,
+                prestige: 1,
+                location: None,
+                event: None,
+            },
+            FishType {
+                id: "ghost_carp".to_string(),
+                name: "Ghost Carp".to_string(),
+                depth: 25,
+                value: 450,
+                min_weight: 10,
+                avg_weight: 30,
+                max_weight: 60,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 0.5,
+                min_rarity: FishRarity::Rare,
+                prestige: 1,
+                location: None,
+                event: None,
+            },
+            FishType {
+                id: "abyssal_leviathan".to_string(),
+                name: "Abyssal Leviathan".to_string(),
+                depth: 140,
+                value: 2500,
+                min_weight: 600,
+                avg_weight: 850,
+                max_weight: 1100,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Legendary,
+                prestige: 2,
+                location: None,
+                event: None,
+            },
+            // Location fish
+            FishType {
+                id: "flounder".to_string(),
+                name: "Flounder".to_string(),
+                depth: 10,
+                value: 20,
+                min_weight: 2,
+                avg_weight: 5,
+                max_weight: 12,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Common,
+                prestige: 0,
+                location: Some("coastal_waters".to_string()),
+                event: None,
+            },
+            FishType {
+                id: "halibut".to_string(),
+                name: "Halibut".to_string(),
+                depth: 40,
+                value: 90,
+                min_weight: 20,
+                avg_weight: 60,
+                max_weight: 200,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: Some("open_ocean".to_string()),
+                event: None,
+            },
+            FishType {
+                id: "giant_squid".to_string(),
+                name: "Giant Squid".to_string(),
+                depth: 180,
+                value: 700,
+                min_weight: 150,
+                avg_weight: 300,
+                max_weight: 600,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Elusive,
+                prestige: 0,
+                location: Some("deep_trench".to_string()),
+                event: None,
+            },
+            // Event fish
+            FishType {
+                id: "arctic_char".to_string(),
+                name: "Arctic Char".to_string(),
+                depth: 15,
+                value: 60,
+                min_weight: 2,
+                avg_weight: 6,
+                max_weight: 20,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Uncommon,
+                prestige: 0,
+                location: None,
+                event: Some("ice_fishing".to_string()),
+            },
+            FishType {
+                id: "burbot".to_string(),
+                name: "Burbot".to_string(),
+                depth: 30,
+                value: 85,
+                min_weight: 3,
+                avg_weight: 10,
+                max_weight: 30,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Rare,
+                prestige: 0,
+                location: None,
+                event: Some("ice_fishing".to_string()),
+            },
+            FishType {
+                id: "ocean_sunfish".to_string(),
+                name: "Ocean Sunfish".to_string(),
+                depth: 40,
+                value: 250,
+                min_weight: 200,
+                avg_weight: 1000,
+                max_weight: 2200,
+                weight_distribution: WeightDistribution::Peaked(2.0),
+                spawn_weight: 1.0,
+                min_rarity: FishRarity::Elusive,
+                prestige: 0,
+                location: None,
+                event: Some("summer_festival".to_string()),
+            },
+        ]),
+        events: event_data().active(),
     }
 }
 
 pub fn rod_data() -> RodData {
     RodData {
-        rods: vec![
+        rods: Catalog::new(vec![
             BaseRod {
+                id: "pisher_frice_plastic_rod".to_string(),
                 name: "Pisher Frice Plastic Rod".to_string(),
                 description: "A plastic rod from Uoys R Tus".to_string(),
                 catch_chance: 0.9,
                 catch_rate: 1.0,
                 depth: 200,
                 weight_limit: 1000,
-                cost: 0.0,
+                cost: Money::new(0),
                 rarity: RodRarity::Unobtainable,
+                prestige: 0,
             },
             BaseRod {
+                id: "stick_with_string".to_string(),
                 name: "Stick with String".to_string(),
                 description: "A stick with a string tied to it. It gets the job done.".to_string(),
                 catch_chance: 0.32,
                 catch_rate: 24.0,
                 depth: 15,
                 weight_limit: 20,
-                cost: 0.0,
+                cost: Money::new(0),
                 rarity: RodRarity::Unobtainable,
+                prestige: 0,
             },
             BaseRod {
+                id: "fiberglass_casting_rod".to_string(),
                 name: "Fiberglass Casting Rod".to_string(),
                 catch_chance: 0.35,
                 catch_rate: 17.2,
                 depth: 20,
                 weight_limit: 40,
-                cost: 200.0,
+                cost: Money::new(200),
                 rarity: RodRarity::Common,
+                prestige: 0,
                 description: "Faster to reel but lower chance of catching fish.".to_string(),
             },
             BaseRod {
+                id: "composite_casting_rod".to_string(),
                 name: "Composite Casting Rod".to_string(),
                 catch_chance: 0.38,
                 catch_rate: 16.5,
                 depth: 25,
                 weight_limit: 45,
-                cost: 500.0,
+                cost: Money::new(500),
                 rarity: RodRarity::Uncommon,
+                prestige: 0,
                 description: "Faster to reel but lower chance of catching fish.".to_string(),
             },
             BaseRod {
+                id: "bamboo_casting_rod".to_string(),
                 name: "Bamboo Casting Rod".to_string(),
                 catch_chance: 0.42,
                 catch_rate: 15.0,
                 depth: 30,
                 weight_limit: 50,
-                cost: 1000.0,
+                cost: Money::new(1000),
                 rarity: RodRarity::Uncommon,
+                prestige: 0,
                 description: "Faster to reel but lower chance of catching fish.".to_string(),
             },
             // Second set of rods
             BaseRod {
+                id: "fiberglass_spinning_rod".to_string(),
                 name: "Fiberglass Spinning Rod".to_string(),
                 catch_chance: 0.4,
                 catch_rate: 18.0,
                 depth: 20,
                 weight_limit: 40,
-                cost: 250.0,
+                cost: Money::new(250),
                 rarity: RodRarity::Common,
+                prestige: 0,
                 description: "Slow to reel but higher chance of catching fish.".to_string(),
             },
             BaseRod {
+                id: "composite_spinning_rod".to_string(),
                 name: "Composite Spinning Rod".to_string(),
                 catch_chance: 0.45,
                 catch_rate: 17.5,
                 depth: 25,
                 weight_limit: 45,
-                cost: 500.0,
+                cost: Money::new(500),
                 rarity: RodRarity::Uncommon,
+                prestige: 0,
                 description: "Slow to reel but higher chance of catching fish.".to_string(),
             },
             BaseRod {
+                id: "bamboo_spinning_rod".to_string(),
                 name: "Bamboo Spinning Rod".to_string(),
                 catch_chance: 0.5,
                 catch_rate: 16.0,
                 depth: 30,
                 weight_limit: 50,
-                cost: 1000.0,
+                cost: Money::new(1000),
                 rarity: RodRarity::Uncommon,
+                prestige: 0,
                 description: "Slow to reel but higher chance of catching fish.".to_string(),
             },
             // Third set of rods
             BaseRod {
+                id: "composite_overhead_rod".to_string(),
                 name: "Composite Overhead Rod".to_string(),
                 catch_chance: 0.65,
                 catch_rate: 15.0,
                 depth: 80,
                 weight_limit: 300,
-                cost: 4000.0,
+                cost: Money::new(4000),
                 rarity: RodRarity::Rare,
+                prestige: 0,
                 description: "Slower to reel but can catch the deepest fish with the best catch rates.".to_string(),
             },
             BaseRod {
+                id: "graphite_overhead_rod".to_string(),
                 name: "Graphite Overhead Rod".to_string(),
                 catch_chance: 0.7,
                 catch_rate: 12.0,
                 depth: 100,
                 weight_limit: 600,
-                cost: 5000.0,
+                cost: Money::new(5000),
                 rarity: RodRarity::Rare,
+                prestige: 0,
                 description: "Slower to reel but can catch the deepest fish with the best catch rates.".to_string(),
             },
             BaseRod {
+                id: "titanium_overhead_rod".to_string(),
                 name: "Titanium Overhead Rod".to_string(),
                 catch_chance: 0.75,
                 catch_rate: 10.0,
                 depth: 150,
                 weight_limit: 1000,
-                cost: 8000.0,
+                cost: Money::new(8000),
                 rarity: RodRarity::Epic,
+                prestige: 0,
                 description: "Slower to reel but can catch the deepest fish with the best catch rates.".to_string(),
             },
             // Fourth set of rods
             BaseRod {
+                id: "composite_fly_rod".to_string(),
                 name: "Composite Fly Rod".to_string(),
                 catch_chance: 0.5,
                 catch_rate: 8.0,
                 depth: 45,
                 weight_limit: 250,
-                cost: 4000.0,
+                cost: Money::new(4000),
                 rarity: RodRarity::Rare,
+                prestige: 0,
                 description: "Incredibly quick catch rates but can't reach deeper waters.".to_string(),
             },
             BaseRod {
+                id: "bamboo_fly_rod".to_string(),
                 name: "Bamboo Fly Rod".to_string(),
                 catch_chance: 0.55,
                 catch_rate: 5.0,
                 depth: 50,
                 weight_limit: 320,
-                cost: 5000.0,
+                cost: Money::new(5000),
                 rarity: RodRarity::Rare,
+                prestige: 0,
                 description: "Incredibly quick catch rates but can't reach deeper waters.".to_string(),
             },
             BaseRod {
+                id: "graphite_fly_rod".to_string(),
                 name: "Graphite Fly Rod".to_string(),
                 catch_chance: 0.6,
                 catch_rate: 3.0,
                 depth: 80,
                 weight_limit: 450,
-                cost: 8000.0,
+                cost: Money::new(8000),
                 rarity: RodRarity::Epic,
+                prestige: 0,
                 description: "Incredibly quick catch rates but can't reach deeper waters.".to_string(),
             },
-        ],
-        modifiers: vec![
+            // Prestige rods
+            BaseRod {
+                id: "carbon_fiber_prestige_rod".to_string(),
+                name: "Carbon Fiber Prestige Rod".to_string(),
+                catch_chance: 0.72,
+                catch_rate: 6.0,
+                depth: 120,
+                weight_limit: 800,
+                cost: Money::new(20000),
+                rarity: RodRarity::Unobtainable,
+                prestige: 1,
+                description: "Only sold to anglers who have prestiged.".to_string(),
+            },
+            BaseRod {
+                id: "abyssal_prestige_rod".to_string(),
+                name: "Abyssal Prestige Rod".to_string(),
+                catch_chance: 0.8,
+                catch_rate: 4.0,
+                depth: 160,
+                weight_limit: 1200,
+                cost: Money::new(50000),
+                rarity: RodRarity::Unobtainable,
+                prestige: 2,
+                description: "Forged for the deepest waters. Only sold to anglers who have prestiged twice.".to_string(),
+            },
+            // Event rods, bought with event currency
+            BaseRod {
+                id: "ice_auger_rod".to_string(),
+                name: "Ice Auger Rod".to_string(),
+                catch_chance: 0.6,
+                catch_rate: 7.0,
+                depth: 60,
+                weight_limit: 350,
+                // priced in the event's currency instead
+                cost: Money::new(0),
+                rarity: RodRarity::Event,
+                prestige: 0,
+                description: "A short, stiff rod made for fishing through a hole in the ice.".to_string(),
+            },
+            BaseRod {
+                id: "monster_hunter_rod".to_string(),
+                name: "Monster Hunter Rod".to_string(),
+                catch_chance: 0.65,
+                catch_rate: 10.0,
+                depth: 150,
+                weight_limit: 1500,
+                // priced in the event's currency instead
+                cost: Money::new(0),
+                rarity: RodRarity::Event,
+                prestige: 0,
+                description: "Built by believers, strong enough to land whatever lives in the loch.".to_string(),
+            },
+            BaseRod {
+                id: "beachcomber_rod".to_string(),
+                name: "Beachcomber Rod".to_string(),
+                catch_chance: 0.6,
+                catch_rate: 5.0,
+                depth: 45,
+                weight_limit: 2500,
+                // priced in the event's currency instead
+                cost: Money::new(0),
+                rarity: RodRarity::Event,
+                prestige: 0,
+                description: "A long surf rod with enough backbone for a summer sunfish.".to_string(),
+            },
+        ]),
+        modifiers: Catalog::new(vec![
+            // Common modifiers
             RodModifier {
+                id: "old".to_string(),
                 name: "Old".to_string(),
+                tier: ModifierTier::Common,
                 catch_rate: 3.0,
                 catch_chance: -0.02,
                 depth: -5,
                 weight_limit: -5,
             },
             RodModifier {
+                id: "worn".to_string(),
+                name: "Worn".to_string(),
+                tier: ModifierTier::Common,
+                catch_rate: 1.0,
+                catch_chance: -0.01,
+                depth: -2,
+                weight_limit: -2,
+            },
+            RodModifier {
+                id: "sturdy".to_string(),
+                name: "Sturdy".to_string(),
+                tier: ModifierTier::Common,
+                catch_rate: 1.5,
+                catch_chance: 0.0,
+                depth: 0,
+                weight_limit: 10,
+            },
+            RodModifier {
+                id: "light".to_string(),
+                name: "Light".to_string(),
+                tier: ModifierTier::Common,
+                catch_rate: -1.5,
+                catch_chance: 0.0,
+                depth: 0,
+                weight_limit: -10,
+            },
+            // Uncommon modifiers
+            RodModifier {
+                id: "better".to_string(),
                 name: "Better".to_string(),
+                tier: ModifierTier::Uncommon,
                 catch_rate: -1.0,
                 catch_chance: 0.02,
                 depth: 5,
                 weight_limit: 5,
             },
             RodModifier {
+                id: "sharp".to_string(),
+                name: "Sharp".to_string(),
+                tier: ModifierTier::Uncommon,
+                catch_rate: 0.0,
+                catch_chance: 0.05,
+                depth: 0,
+                weight_limit: 0,
+            },
+            RodModifier {
+                id: "weighted".to_string(),
+                name: "Weighted".to_string(),
+                tier: ModifierTier::Uncommon,
+                catch_rate: 2.0,
+                catch_chance: 0.0,
+                depth: 15,
+                weight_limit: 0,
+            },
+            // Rare modifiers
+            RodModifier {
+                id: "balanced".to_string(),
+                name: "Balanced".to_string(),
+                tier: ModifierTier::Rare,
+                catch_rate: -1.5,
+                catch_chance: 0.04,
+                depth: 10,
+                weight_limit: 10,
+            },
+            RodModifier {
+                id: "heavy_duty".to_string(),
+                name: "Heavy Duty".to_string(),
+                tier: ModifierTier::Rare,
+                catch_rate: 2.5,
+                catch_chance: -0.03,
+                depth: 10,
+                weight_limit: 100,
+            },
+            RodModifier {
+                id: "swift".to_string(),
+                name: "Swift".to_string(),
+                tier: ModifierTier::Rare,
+                catch_rate: -4.0,
+                catch_chance: -0.03,
+                depth: 0,
+                weight_limit: 0,
+            },
+            // Epic modifiers
+            RodModifier {
+                id: "upgraded".to_string(),
                 name: "Upgraded".to_string(),
+                tier: ModifierTier::Epic,
                 catch_rate: -3.0,
                 catch_chance: 0.2,
                 depth: 20,
                 weight_limit: 20,
             },
+            RodModifier {
+                id: "deep_diving".to_string(),
+                name: "Deep Diving".to_string(),
+                tier: ModifierTier::Epic,
+                catch_rate: 1.0,
+                catch_chance: 0.05,
+                depth: 60,
+                weight_limit: 50,
+            },
+            // Legendary modifiers
+            RodModifier {
+                id: "masterwork".to_string(),
+                name: "Masterwork".to_string(),
+                tier: ModifierTier::Legendary,
+                catch_rate: -5.0,
+                catch_chance: 0.25,
+                depth: 40,
+                weight_limit: 150,
+            },
+            // Exclusive modifiers
+            RodModifier {
+                id: "abyssal".to_string(),
+                name: "Abyssal".to_string(),
+                tier: ModifierTier::Exclusive,
+                catch_rate: 0.0,
+                catch_chance: 0.1,
+                depth: 80,
+                weight_limit: 100,
+            },
+            RodModifier {
+                id: "mythic".to_string(),
+                name: "Mythic".to_string(),
+                tier: ModifierTier::Exclusive,
+                catch_rate: -6.0,
+                catch_chance: 0.3,
+                depth: 50,
+                weight_limit: 250,
+            },
+        ]),
+    }
+}
+
+pub fn achievement_data() -> AchievementData {
+    AchievementData {
+        achievements: vec![
+            Achievement {
+                id: "first_catch".to_string(),
+                name: "First Catch".to_string(),
+                description: "Catch your first fish".to_string(),
+                reward: Money::new(10),
+                condition: AchievementCondition::FishCaught(1),
+            },
+            Achievement {
+                id: "catch_100".to_string(),
+                name: "Seasoned Angler".to_string(),
+                description: "Catch 100 fish".to_string(),
+                reward: Money::new(500),
+                condition: AchievementCondition::FishCaught(100),
+            },
+            Achievement {
+                id: "catch_1000".to_string(),
+                name: "Master Angler".to_string(),
+                description: "Catch 1000 fish".to_string(),
+                reward: Money::new(5000),
+                condition: AchievementCondition::FishCaught(1000),
+            },
+            Achievement {
+                id: "catch_rare".to_string(),
+                name: "Rare Find".to_string(),
+                description: "Catch a Rare fish".to_string(),
+                reward: Money::new(50),
+                condition: AchievementCondition::CatchRarity(FishRarity::Rare),
+            },
+            Achievement {
+                id: "catch_legendary".to_string(),
+                name: "The Stuff of Legends".to_string(),
+                description: "Catch a Legendary fish".to_string(),
+                reward: Money::new(1000),
+                condition: AchievementCondition::CatchRarity(FishRarity::Legendary),
+            },
+            Achievement {
+                id: "catch_mythical".to_string(),
+                name: "Myth Buster".to_string(),
+                description: "Catch a Mythical fish".to_string(),
+                reward: Money::new(10000),
+                condition: AchievementCondition::CatchRarity(FishRarity::Mythical),
+            },
+            Achievement {
+                id: "catch_nessie".to_string(),
+                name: "Nessie!".to_string(),
+                description: "Catch the Loch Ness Monster".to_string(),
+                reward: Money::new(5000),
+                condition: AchievementCondition::CatchSpecies("loch_ness_monster".to_string()),
+            },
+            Achievement {
+                id: "catch_heavy".to_string(),
+                name: "Heavyweight".to_string(),
+                description: "Catch a fish weighing 300lbs or more".to_string(),
+                reward: Money::new(750),
+                condition: AchievementCondition::CatchWeight(300),
+            },
+            Achievement {
+                id: "unique_10".to_string(),
+                name: "Curious".to_string(),
+                description: "See 10 different fish".to_string(),
+                reward: Money::new(100),
+                condition: AchievementCondition::UniqueFish(10),
+            },
+            Achievement {
+                id: "bestiary_complete".to_string(),
+                name: "Completionist".to_string(),
+                description: "Complete the bestiary, event and prestige fish not included".to_string(),
+                reward: Money::new(10000),
+                condition: AchievementCondition::BestiaryComplete,
+            },
+            Achievement {
+                id: "turtle_1".to_string(),
+                name: "Turtle Trouble".to_string(),
+                description: "Lose a fish to a turtle".to_string(),
+                reward: Money::new(25),
+                condition: AchievementCondition::EventCount { event: "turtle".to_string(), count: 1 },
+            },
+            Achievement {
+                id: "turtle_10".to_string(),
+                name: "Turtle Nemesis".to_string(),
+                description: "Lose 10 fish to turtles".to_string(),
+                reward: Money::new(500),
+                condition: AchievementCondition::EventCount { event: "turtle".to_string(), count: 10 },
+            },
+            Achievement {
+                id: "turtle_legendary".to_string(),
+                name: "Shell Shocked".to_string(),
+                description: "Lose a Legendary fish to a turtle".to_string(),
+                reward: Money::new(1000),
+                condition: AchievementCondition::EventRarity { event: "turtle".to_string(), rarity: FishRarity::Legendary },
+            },
+            Achievement {
+                id: "line_break_1".to_string(),
+                name: "Snap!".to_string(),
+                description: "Break your line on a heavy fish".to_string(),
+                reward: Money::new(25),
+                condition: AchievementCondition::LineBreaks(1),
+            },
+            Achievement {
+                id: "line_break_25".to_string(),
+                name: "Needs a Stronger Line".to_string(),
+                description: "Break your line 25 times".to_string(),
+                reward: Money::new(250),
+                condition: AchievementCondition::LineBreaks(25),
+            },
+            Achievement {
+                id: "line_break_heavy".to_string(),
+                name: "The One That Got Away".to_string(),
+                description: "Break your line on a fish weighing 500lbs or more".to_string(),
+                reward: Money::new(500),
+                condition: AchievementCondition::LineBreakWeight(500),
+            },
+            Achievement {
+                id: "first_rod".to_string(),
+                name: "Shopper".to_string(),
+                description: "Buy your first rod".to_string(),
+                reward: Money::new(50),
+                condition: AchievementCondition::RodsBought(1),
+            },
+            Achievement {
+                id: "titanium_rod".to_string(),
+                name: "Top of the Line".to_string(),
+                description: "Buy a Titanium Overhead Rod".to_string(),
+                reward: Money::new(1000),
+                condition: AchievementCondition::BuyRod("titanium_overhead_rod".to_string()),
+            },
         ],
     }
-}
\ No newline at end of file
+}
+
+pub fn skill_data() -> SkillData {
+    SkillData {
+        skills: vec![
+            Skill {
+                id: "patience".to_string(),
+                name: "Patience".to_string(),
+                description: "+2% catch chance per rank".to_string(),
+                max_rank: 5,
+                requires: None,
+                effect: SkillEffect::CatchChance(0.02),
+            },
+            Skill {
+                id: "fast_reel".to_string(),
+                name: "Fast Reel".to_string(),
+                description: "Reel in 4% faster per rank".to_string(),
+                max_rank: 5,
+                requires: Some("patience".to_string()),
+                effect: SkillEffect::FasterReel(0.04),
+            },
+            Skill {
+                id: "steady_hands".to_string(),
+                name: "Steady Hands".to_string(),
+                description: "+3% catch chance per rank".to_string(),
+                max_rank: 5,
+                requires: Some("patience".to_string()),
+                effect: SkillEffect::CatchChance(0.03),
+            },
+            Skill {
+                id: "strong_arms".to_string(),
+                name: "Strong Arms".to_string(),
+                description: "+5% weight limit per rank".to_string(),
+                max_rank: 5,
+                requires: None,
+                effect: SkillEffect::WeightTolerance(0.05),
+            },
+            Skill {
+                id: "heavy_lifter".to_string(),
+                name: "Heavy Lifter".to_string(),
+                description: "+10% weight limit per rank".to_string(),
+                max_rank: 3,
+                requires: Some("strong_arms".to_string()),
+                effect: SkillEffect::WeightTolerance(0.1),
+            },
+            Skill {
+                id: "turtle_repellent".to_string(),
+                name: "Turtle Repellent".to_string(),
+                description: "25% less likely to lose fish to turtles per rank".to_string(),
+                max_rank: 4,
+                requires: None,
+                effect: SkillEffect::TurtleRepellent(0.25),
+            },
+            Skill {
+                id: "lucky_buyer".to_string(),
+                name: "Lucky Buyer".to_string(),
+                description: "25% chance per rank for modifiers on bought and reforged rods to avoid drawbacks".to_string(),
+                max_rank: 4,
+                requires: None,
+                effect: SkillEffect::ShopLuck(0.25),
+            },
+        ],
+    }
+}
+
+pub fn component_data() -> ComponentData {
+    ComponentData {
+        components: vec![
+            // Blanks
+            RodComponent {
+                name: "Pine Blank".to_string(),
+                kind: ComponentKind::Blank,
+                catch_chance: 0.2,
+                catch_rate: 14.0,
+                depth: 10,
+                weight_limit: 15,
+                cost: Money::new(150),
+                rarity: RodRarity::Common,
+            },
+            RodComponent {
+                name: "Fiberglass Blank".to_string(),
+                kind: ComponentKind::Blank,
+                catch_chance: 0.25,
+                catch_rate: 12.0,
+                depth: 15,
+                weight_limit: 25,
+                cost: Money::new(400),
+                rarity: RodRarity::Uncommon,
+            },
+            RodComponent {
+                name: "Graphite Blank".to_string(),
+                kind: ComponentKind::Blank,
+                catch_chance: 0.3,
+                catch_rate: 9.0,
+                depth: 30,
+                weight_limit: 60,
+                cost: Money::new(1500),
+                rarity: RodRarity::Rare,
+            },
+            RodComponent {
+                name: "Titanium Blank".to_string(),
+                kind: ComponentKind::Blank,
+                catch_chance: 0.35,
+                catch_rate: 7.0,
+                depth: 50,
+                weight_limit: 120,
+                cost: Money::new(4000),
+                rarity: RodRarity::Epic,
+            },
+            // Reels
+            RodComponent {
+                name: "Spincast Reel".to_string(),
+                kind: ComponentKind::Reel,
+                catch_chance: 0.05,
+                catch_rate: 6.0,
+                depth: 0,
+                weight_limit: 0,
+                cost: Money::new(100),
+                rarity: RodRarity::Common,
+            },
+            RodComponent {
+                name: "Spinning Reel".to_string(),
+                kind: ComponentKind::Reel,
+                catch_chance: 0.08,
+                catch_rate: 4.0,
+                depth: 0,
+                weight_limit: 0,
+                cost: Money::new(350),
+                rarity: RodRarity::Uncommon,
+            },
+            RodComponent {
+                name: "Baitcasting Reel".to_string(),
+                kind: ComponentKind::Reel,
+                catch_chance: 0.1,
+                catch_rate: 2.0,
+                depth: 10,
+                weight_limit: 0,
+                cost: Money::new(1200),
+                rarity: RodRarity::Rare,
+            },
+            RodComponent {
+                name: "Conventional Reel".to_string(),
+                kind: ComponentKind::Reel,
+                catch_chance: 0.12,
+                catch_rate: 1.0,
+                depth: 20,
+                weight_limit: 50,
+                cost: Money::new(3500),
+                rarity: RodRarity::Epic,
+            },
+            // Lines
+            RodComponent {
+                name: "Monofilament Line".to_string(),
+                kind: ComponentKind::Line,
+                catch_chance: 0.0,
+                catch_rate: 0.0,
+                depth: 5,
+                weight_limit: 10,
+                cost: Money::new(80),
+                rarity: RodRarity::Common,
+            },
+            RodComponent {
+                name: "Fluorocarbon Line".to_string(),
+                kind: ComponentKind::Line,
+                catch_chance: 0.0,
+                catch_rate: 0.0,
+                depth: 15,
+                weight_limit: 60,
+                cost: Money::new(300),
+                rarity: RodRarity::Uncommon,
+            },
+            RodComponent {
+                name: "Braided Line".to_string(),
+                kind: ComponentKind::Line,
+                catch_chance: 0.0,
+                catch_rate: 0.0,
+                depth: 30,
+                weight_limit: 200,
+                cost: Money::new(1000),
+                rarity: RodRarity::Rare,
+            },
+            RodComponent {
+                name: "Kevlar Line".to_string(),
+                kind: ComponentKind::Line,
+                catch_chance: 0.0,
+                catch_rate: 0.0,
+                depth: 50,
+                weight_limit: 500,
+                cost: Money::new(3000),
+                rarity: RodRarity::Epic,
+            },
+            // Hooks
+            RodComponent {
+                name: "J Hook".to_string(),
+                kind: ComponentKind::Hook,
+                catch_chance: 0.05,
+                catch_rate: 0.0,
+                depth: 0,
+                weight_limit: 0,
+                cost: Money::new(50),
+                rarity: RodRarity::Common,
+            },
+            RodComponent {
+                name: "Circle Hook".to_string(),
+                kind: ComponentKind::Hook,
+                catch_chance: 0.1,
+                catch_rate: 0.0,
+                depth: 0,
+                weight_limit: 0,
+                cost: Money::new(200),
+                rarity: RodRarity::Uncommon,
+            },
+            RodComponent {
+                name: "Treble Hook".to_string(),
+                kind: ComponentKind::Hook,
+                catch_chance: 0.15,
+                catch_rate: 0.0,
+                depth: 0,
+                weight_limit: 0,
+                cost: Money::new(800),
+                rarity: RodRarity::Rare,
+            },
+            RodComponent {
+                name: "Barbed Titanium Hook".to_string(),
+                kind: ComponentKind::Hook,
+                catch_chance: 0.2,
+                catch_rate: 0.0,
+                depth: 0,
+                weight_limit: 0,
+                cost: Money::new(2500),
+                rarity: RodRarity::Epic,
+            },
+        ],
+    }
+}
+
+pub fn tackle_data() -> TackleData {
+    TackleData {
+        tackle: vec![
+            // Lines
+            Tackle {
+                name: "Light Line".to_string(),
+                slot: TackleSlot::Line,
+                bonus: 10.0,
+                upgrade_bonus: 5.0,
+                max_level: 5,
+                cost: Money::new(150),
+            },
+            Tackle {
+                name: "Heavy Line".to_string(),
+                slot: TackleSlot::Line,
+                bonus: 50.0,
+                upgrade_bonus: 20.0,
+                max_level: 5,
+                cost: Money::new(1200),
+            },
+            Tackle {
+                name: "Steel Leader".to_string(),
+                slot: TackleSlot::Line,
+                bonus: 150.0,
+                upgrade_bonus: 50.0,
+                max_level: 5,
+                cost: Money::new(5000),
+            },
+            // Reels
+            Tackle {
+                name: "Smooth Reel".to_string(),
+                slot: TackleSlot::Reel,
+                bonus: 0.5,
+                upgrade_bonus: 0.25,
+                max_level: 5,
+                cost: Money::new(200),
+            },
+            Tackle {
+                name: "Ball Bearing Reel".to_string(),
+                slot: TackleSlot::Reel,
+                bonus: 1.5,
+                upgrade_bonus: 0.5,
+                max_level: 5,
+                cost: Money::new(1500),
+            },
+            Tackle {
+                name: "High Speed Reel".to_string(),
+                slot: TackleSlot::Reel,
+                bonus: 3.0,
+                upgrade_bonus: 0.75,
+                max_level: 5,
+                cost: Money::new(6000),
+            },
+            // Hooks
+            Tackle {
+                name: "Sharp Hook".to_string(),
+                slot: TackleSlot::Hook,
+                bonus: 0.02,
+                upgrade_bonus: 0.01,
+                max_level: 5,
+                cost: Money::new(150),
+            },
+            Tackle {
+                name: "Chemically Sharpened Hook".to_string(),
+                slot: TackleSlot::Hook,
+                bonus: 0.05,
+                upgrade_bonus: 0.015,
+                max_level: 5,
+                cost: Money::new(1200),
+            },
+            Tackle {
+                name: "Laser Hook".to_string(),
+                slot: TackleSlot::Hook,
+                bonus: 0.1,
+                upgrade_bonus: 0.02,
+                max_level: 5,
+                cost: Money::new(5000),
+            },
+            // Sinkers
+            Tackle {
+                name: "Split Shot".to_string(),
+                slot: TackleSlot::Sinker,
+                bonus: 5.0,
+                upgrade_bonus: 3.0,
+                max_level: 5,
+                cost: Money::new(100),
+            },
+            Tackle {
+                name: "Egg Sinker".to_string(),
+                slot: TackleSlot::Sinker,
+                bonus: 20.0,
+                upgrade_bonus: 5.0,
+                max_level: 5,
+                cost: Money::new(1000),
+            },
+            Tackle {
+                name: "Cannonball Sinker".to_string(),
+                slot: TackleSlot::Sinker,
+                bonus: 50.0,
+                upgrade_bonus: 10.0,
+                max_level: 5,
+                cost: Money::new(5000),
+            },
+        ],
+    }
+}
+
+pub fn boat_data() -> BoatData {
+    BoatData {
+        boats: Catalog::new(vec![
+            Boat {
+                id: "rowboat".to_string(),
+                name: "Rowboat".to_string(),
+                description: "Gets you off the shore, if you don't mind the rowing.".to_string(),
+                tier: 1,
+                depth: 20,
+                cost: Money::new(2500),
+                upkeep: Money::new(25),
+            },
+            Boat {
+                id: "trawler".to_string(),
+                name: "Trawler".to_string(),
+                description: "A sturdy fishing boat that can handle the open ocean.".to_string(),
+                tier: 2,
+                depth: 60,
+                cost: Money::new(15000),
+                upkeep: Money::new(150),
+            },
+            Boat {
+                id: "research_vessel".to_string(),
+                name: "Research Vessel".to_string(),
+                description: "Fitted out for expeditions far over the deepest water.".to_string(),
+                tier: 3,
+                depth: 120,
+                cost: Money::new(60000),
+                upkeep: Money::new(600),
+            },
+        ]),
+        locations: Catalog::new(vec![
+            Location {
+                id: "lakeshore".to_string(),
+                name: "Lakeshore".to_string(),
+                description: "The local lake, no boat needed.".to_string(),
+                tier: 0,
+            },
+            Location {
+                id: "coastal_waters".to_string(),
+                name: "Coastal Waters".to_string(),
+                description: "Shallow water just off the coast.".to_string(),
+                tier: 1,
+            },
+            Location {
+                id: "open_ocean".to_string(),
+                name: "Open Ocean".to_string(),
+                description: "Far from land, where the big fish roam.".to_string(),
+                tier: 2,
+            },
+            Location {
+                id: "deep_trench".to_string(),
+                name: "Deep Trench".to_string(),
+                description: "Miles out over the deepest water on the map.".to_string(),
+                tier: 3,
+            },
+        ]),
+    }
+}
+
+pub fn collection_data() -> CollectionData {
+    let mut sets = vec![
+        CollectionSet {
+            id: "junk".to_string(),
+            name: "Junk Drawer".to_string(),
+            description: "Fish every piece of trash out of the lake.".to_string(),
+            requirement: SetRequirement::Items(vec![
+                "old_boot".to_string(),
+                "tin_can".to_string(),
+                "seaweed".to_string(),
+                "plastic_bag".to_string(),
+                "plastic_bottle".to_string(),
+                "tire".to_string(),
+            ]),
+            reward: QuestReward::Money(Money::new(500)),
+        },
+        CollectionSet {
+            id: "freshwater".to_string(),
+            name: "Freshwater Regular".to_string(),
+            description: "Catch the common fish of lakes and rivers.".to_string(),
+            requirement: SetRequirement::Species(vec![
+                "trout".to_string(),
+                "rainbow_trout".to_string(),
+                "bass".to_string(),
+                "perch".to_string(),
+                "catfish".to_string(),
+                "pike".to_string(),
+                "carp".to_string(),
+                "tilapia".to_string(),
+            ]),
+            reward: QuestReward::Money(Money::new(2500)),
+        },
+        CollectionSet {
+            id: "deep_sea".to_string(),
+            name: "Deep Sea Explorer".to_string(),
+            description: "Catch the strange things living far below.".to_string(),
+            requirement: SetRequirement::Species(vec![
+                "anglerfish".to_string(),
+                "lanternfish".to_string(),
+                "fangtooth".to_string(),
+                "viperfish".to_string(),
+                "daggertooth".to_string(),
+                "barracudina".to_string(),
+                "antarctic_toothfish".to_string(),
+            ]),
+            reward: QuestReward::Modifier("abyssal".to_string()),
+        },
+        CollectionSet {
+            id: "legends".to_string(),
+            name: "Cryptozoologist".to_string(),
+            description: "Prove the legends are real.".to_string(),
+            requirement: SetRequirement::Species(vec![
+                "loch_ness_monster".to_string(),
+                "golden_koi".to_string(),
+                "ghost_carp".to_string(),
+                "abyssal_leviathan".to_string(),
+            ]),
+            reward: QuestReward::Modifier("mythic".to_string()),
+        },
+    ];
+
+    // every species gets a set for catching it at each rarity it can appear at, short of mythical
+    for fish in fish_data().fish.iter() {
+        let rarities: Vec<_> = FishRarity::all().into_iter()
+            .filter(|r| r.ident() >= fish.min_rarity.ident() && r.ident() < FishRarity::Mythical.ident())
+            .collect();
+        if rarities.len() < 2 {
+            continue;
+        }
+
+        sets.push(CollectionSet {
+            id: format!("rarity_{}", fish.id),
+            name: format!("{} Variety", fish),
+            description: format!("Catch a {} at every rarity.", fish),
+            reward: QuestReward::Money(Money::new(u64::from((fish.value * 20).max(250)))),
+            requirement: SetRequirement::Rarities { species: fish.id.clone(), rarities },
+        });
+    }
+
+    CollectionData { sets }
+}
+
+pub fn event_data() -> EventData {
+    EventData {
+        events: vec![
+            FishingEvent {
+                id: "ice_fishing".to_string(),
+                name: "Winter Ice Fishing".to_string(),
+                description: "The lake has frozen over! Cold water fish are biting through the ice.".to_string(),
+                start: CalendarDay { month: 12, day: 15 },
+                end: CalendarDay { month: 1, day: 15 },
+                rarity_boosts: vec![],
+                species_boosts: vec![("trout".to_string(), 2.0), ("pike".to_string(), 2.0)],
+                currency: "Snowflakes".to_string(),
+                shop: vec![("ice_auger_rod".to_string(), 150)],
+            },
+            FishingEvent {
+                id: "loch_ness_week".to_string(),
+                name: "Loch Ness Week".to_string(),
+                description: "Sightings are pouring in. The monster has never been easier to catch.".to_string(),
+                start: CalendarDay { month: 4, day: 21 },
+                end: CalendarDay { month: 4, day: 27 },
+                rarity_boosts: vec![(FishRarity::Mythical, 10.0)],
+                species_boosts: vec![("loch_ness_monster".to_string(), 5.0)],
+                currency: "Monster Scales".to_string(),
+                shop: vec![("monster_hunter_rod".to_string(), 100)],
+            },
+            FishingEvent {
+                id: "summer_festival".to_string(),
+                name: "Summer Festival".to_string(),
+                description: "Warm water brings rare visitors close to shore.".to_string(),
+                start: CalendarDay { month: 7, day: 1 },
+                end: CalendarDay { month: 7, day: 14 },
+                rarity_boosts: vec![(FishRarity::Rare, 1.5), (FishRarity::Elusive, 1.5)],
+                species_boosts: vec![],
+                currency: "Seashells".to_string(),
+                shop: vec![("beachcomber_rod".to_string(), 200)],
+            },
+        ],
+    }
+}
+
+pub fn random_event_data() -> RandomEventData {
+    RandomEventData {
+        events: vec![
+            RandomEvent {
+                id: "turtle".to_string(),
+                chance: 20.0,
+                repellable: true,
+                conditions: vec![],
+                effects: vec![EventEffect::Steal],
+                text: "🐢 A turtle stole your {fish}! 🐢".to_string(),
+                image: Some(egui::include_image!("../../assets/turtle.png")),
+            },
+            RandomEvent {
+                id: "seagull".to_string(),
+                chance: 30.0,
+                repellable: false,
+                conditions: vec![EventCondition::Location("coastal_waters".to_string())],
+                effects: vec![EventEffect::Steal],
+                text: "🐦 A seagull swooped down and snatched your {fish}! 🐦".to_string(),
+                image: None,
+            },
+            RandomEvent {
+                id: "rusty_hook".to_string(),
+                chance: 10.0,
+                repellable: false,
+                conditions: vec![EventCondition::MinWeight(20)],
+                effects: vec![EventEffect::Escape],
+                text: "🪝 Your rusty hook snapped and the {fish} swam off! 🪝".to_string(),
+                image: None,
+            },
+            RandomEvent {
+                id: "double_catch".to_string(),
+                chance: 10.0,
+                repellable: false,
+                conditions: vec![],
+                effects: vec![EventEffect::ValueMultiplier(2.0)],
+                text: "🎣 A second fish was on the line with your {fish}! Double catch! 🎣".to_string(),
+                image: None,
+            },
+            RandomEvent {
+                id: "treasure_chest".to_string(),
+                chance: 5.0,
+                repellable: false,
+                conditions: vec![EventCondition::MinDepth(40)],
+                effects: vec![EventEffect::Money(Money::new(500)), EventEffect::Component(RodRarity::Rare)],
+                text: "💰 Your {fish} was guarding a treasure chest! (+$500 and a rod component) 💰".to_string(),
+                image: None,
+            },
+            RandomEvent {
+                id: "message_in_a_bottle".to_string(),
+                chance: 5.0,
+                repellable: false,
+                conditions: vec![],
+                effects: vec![EventEffect::Xp(100)],
+                text: "📜 A message in a bottle drifted past your {fish}, full of fishing tips! (+100xp) 📜".to_string(),
+                image: None,
+            },
+        ],
+    }
+}
+
+pub fn loot_data() -> LootData {
+    LootData {
+        items: Catalog::new(vec![
+            // Junk
+            LootItem {
+                id: "old_boot".to_string(),
+                name: "Old Boot".to_string(),
+                description: "Someone lost a shoe. It's still wet.".to_string(),
+                kind: LootKind::Junk,
+                weight: 100,
+                depth: 0,
+                recycle_value: Money::new(3),
+            },
+            LootItem {
+                id: "tin_can".to_string(),
+                name: "Tin Can".to_string(),
+                description: "Empty, thankfully.".to_string(),
+                kind: LootKind::Junk,
+                weight: 100,
+                depth: 0,
+                recycle_value: Money::new(2),
+            },
+            LootItem {
+                id: "seaweed".to_string(),
+                name: "Seaweed".to_string(),
+                description: "Slimy and tangled around your hook.".to_string(),
+                kind: LootKind::Junk,
+                weight: 120,
+                depth: 0,
+                recycle_value: Money::new(1),
+            },
+            LootItem {
+                id: "plastic_bag".to_string(),
+                name: "Plastic Bag".to_string(),
+                description: "Better out of the water than in it.".to_string(),
+                kind: LootKind::Junk,
+                weight: 100,
+                depth: 0,
+                recycle_value: Money::new(1),
+            },
+            LootItem {
+                id: "plastic_bottle".to_string(),
+                name: "Plastic Bottle".to_string(),
+                description: "Worth a little at the recycling center.".to_string(),
+                kind: LootKind::Junk,
+                weight: 100,
+                depth: 0,
+                recycle_value: Money::new(2),
+            },
+            LootItem {
+                id: "tire".to_string(),
+                name: "Tire".to_string(),
+                description: "How did this even get here?".to_string(),
+                kind: LootKind::Junk,
+                weight: 40,
+                depth: 0,
+                recycle_value: Money::new(10),
+            },
+            // Treasure
+            LootItem {
+                id: "small_chest".to_string(),
+                name: "Small Chest".to_string(),
+                description: "A waterlogged box with something rattling around inside.".to_string(),
+                kind: LootKind::Treasure(vec![
+                    QuestReward::Money(Money::new(250)),
+                    QuestReward::Money(Money::new(500)),
+                    QuestReward::Modifier("sturdy".to_string()),
+                    QuestReward::Modifier("sharp".to_string()),
+                ]),
+                weight: 15,
+                depth: 20,
+                recycle_value: Money::new(25),
+            },
+            LootItem {
+                id: "sunken_chest".to_string(),
+                name: "Sunken Treasure Chest".to_string(),
+                description: "Heavy, barnacle covered, and definitely not empty.".to_string(),
+                kind: LootKind::Treasure(vec![
+                    QuestReward::Money(Money::new(2000)),
+                    QuestReward::Money(Money::new(5000)),
+                    QuestReward::Modifier("heavy_duty".to_string()),
+                    QuestReward::Modifier("upgraded".to_string()),
+                ]),
+                weight: 5,
+                depth: 80,
+                recycle_value: Money::new(100),
+            },
+        ]),
+    }
+}
+
+pub fn tournament_data() -> TournamentData {
+    TournamentData {
+        tournaments: vec![
+            Tournament {
+                id: "lunker_derby".to_string(),
+                name: "Lakeshore Lunker Derby".to_string(),
+                description: "Ten minutes to land the heaviest fish in the lake.".to_string(),
+                kind: TournamentKind::HeaviestFish,
+                location: "lakeshore".to_string(),
+                minutes: 10,
+                entry_fee: Money::new(100),
+                prizes: vec![Money::new(1000), Money::new(400), Money::new(150)],
+                anglers: vec![
+                    AiAngler {
+                        name: "Old Pete".to_string(),
+                        rod: "fiberglass_spinning_rod".to_string(),
+                        efficiency: 1.0,
+                    },
+                    AiAngler {
+                        name: "Marge".to_string(),
+                        rod: "fiberglass_casting_rod".to_string(),
+                        efficiency: 0.8,
+                    },
+                    AiAngler {
+                        name: "Bubba".to_string(),
+                        rod: "bamboo_casting_rod".to_string(),
+                        efficiency: 0.7,
+                    },
+                    AiAngler {
+                        name: "Kid Kelly".to_string(),
+                        rod: "composite_spinning_rod".to_string(),
+                        efficiency: 0.9,
+                    },
+                ],
+            },
+            Tournament {
+                id: "species_sprint".to_string(),
+                name: "Species Sprint".to_string(),
+                description: "Catch as many different species as you can in fifteen minutes.".to_string(),
+                kind: TournamentKind::MostSpecies,
+                location: "lakeshore".to_string(),
+                minutes: 15,
+                entry_fee: Money::new(250),
+                prizes: vec![Money::new(2500), Money::new(1000), Money::new(400)],
+                anglers: vec![
+                    AiAngler {
+                        name: "Professor Gill".to_string(),
+                        rod: "composite_spinning_rod".to_string(),
+                        efficiency: 0.8,
+                    },
+                    AiAngler {
+                        name: "Marge".to_string(),
+                        rod: "fiberglass_casting_rod".to_string(),
+                        efficiency: 0.8,
+                    },
+                    AiAngler {
+                        name: "Dusty".to_string(),
+                        rod: "bamboo_fly_rod".to_string(),
+                        efficiency: 0.7,
+                    },
+                ],
+            },
+            Tournament {
+                id: "coastal_classic".to_string(),
+                name: "Coastal Cash Classic".to_string(),
+                description: "Whoever brings in the most money in twenty minutes takes the pot.".to_string(),
+                kind: TournamentKind::MostValue,
+                location: "coastal_waters".to_string(),
+                minutes: 20,
+                entry_fee: Money::new(1500),
+                prizes: vec![Money::new(12000), Money::new(5000), Money::new(2000)],
+                anglers: vec![
+                    AiAngler {
+                        name: "Captain Reyes".to_string(),
+                        rod: "graphite_overhead_rod".to_string(),
+                        efficiency: 0.8,
+                    },
+                    AiAngler {
+                        name: "The Twins".to_string(),
+                        rod: "composite_overhead_rod".to_string(),
+                        efficiency: 0.9,
+                    },
+                    AiAngler {
+                        name: "Salty Sue".to_string(),
+                        rod: "graphite_fly_rod".to_string(),
+                        efficiency: 0.8,
+                    },
+                    AiAngler {
+                        name: "Bubba".to_string(),
+                        rod: "composite_casting_rod".to_string(),
+                        efficiency: 0.7,
+                    },
+                    AiAngler {
+                        name: "Professor Gill".to_string(),
+                        rod: "titanium_overhead_rod".to_string(),
+                        efficiency: 0.6,
+                    },
+                ],
+            },
+        ],
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use serde::{Deserialize, Serialize};
+
+/// suffixes for amounts of a million dollars and up, each a thousand times the last
+const SUFFIXES: [&str; 5] = ["M", "B", "T", "Qa", "Qi"];
+
+/// An amount of whole dollars. Additions cap out at the largest amount instead of wrapping
+/// and subtractions are checked, so a balance can't overflow or go negative
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Money(u64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+    pub const MAX: Money = Money(u64::MAX);
+
+    pub const fn new(dollars: u64) -> Self {
+        Self(dollars)
+    }
+
+    /// Rounds a calculated amount to whole dollars, anything below zero is free
+    pub fn from_f32(amount: f32) -> Self {
+        // float to int casts saturate, so huge amounts become `MAX`
+        Self(amount.max(0.0).round() as u64)
+    }
+
+    pub fn dollars(self) -> u64 {
+        self.0
+    }
+
+    pub fn as_f32(self) -> f32 {
+        self.0 as f32
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    /// `None` if `other` is more than this amount
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+
+    /// Multiplies the amount, e.g. by a discount or a bonus, rounding to whole dollars
+    pub fn scale(self, factor: f32) -> Money {
+        Self::from_f32(self.0 as f32 * factor)
+    }
+
+    pub fn times(self, count: u64) -> Money {
+        Money(self.0.saturating_mul(count))
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        self.checked_add(other).unwrap_or(Money::MAX)
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+/// Groups the digits of `amount` in threes, e.g. 1234567 -> "1,234,567"
+fn separated(amount: u64) -> String {
+    let digits = amount.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(digit);
+    }
+    out
+}
+
+impl Display for Money {
+    /// "$999,999" below a million, then "$1.2M", "$3.4B" and so on. Suffixed amounts are
+    /// rounded down so a balance never shows more than the player actually has
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut unit = 1_000_000u64;
+        if self.0 < unit {
+            return write!(f, "${}", separated(self.0));
+        }
+
+        for (i, suffix) in SUFFIXES.iter().enumerate() {
+            if i + 1 == SUFFIXES.len() || self.0 / 1000 < unit {
+                let tenths = self.0 / (unit / 10);
+                return write!(f, "${}.{}{}", separated(tenths / 10), tenths % 10, suffix);
+            }
+            unit *= 1000;
+        }
+        unreachable!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_amounts_are_separated() {
+        assert_eq!(Money::new(0).to_string(), "$0");
+        assert_eq!(Money::new(999).to_string(), "$999");
+        assert_eq!(Money::new(1000).to_string(), "$1,000");
+        assert_eq!(Money::new(999_999).to_string(), "$999,999");
+    }
+
+    #[test]
+    fn large_amounts_are_suffixed() {
+        assert_eq!(Money::new(1_000_000).to_string(), "$1.0M");
+        assert_eq!(Money::new(1_250_000).to_string(), "$1.2M");
+        assert_eq!(Money::new(999_999_999).to_string(), "$999.9M");
+        assert_eq!(Money::new(3_400_000_000).to_string(), "$3.4B");
+        assert_eq!(Money::MAX.to_string(), "$18.4Qi");
+    }
+
+    #[test]
+    fn arithmetic_never_wraps() {
+        assert_eq!(Money::MAX + Money::new(1), Money::MAX);
+        assert_eq!(Money::new(5).checked_sub(Money::new(6)), None);
+        assert_eq!(Money::new(6).checked_sub(Money::new(5)), Some(Money::new(1)));
+        assert_eq!(Money::new(100).scale(0.85), Money::new(85));
+        assert_eq!(Money::from_f32(-3.0), Money::ZERO);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::data::fish::FishData;
+use crate::data::money::Money;
+use crate::data::shop::RodRarity;
+use crate::data::skills::AnglerBonuses;
+use crate::data::stat_pipeline::{Stat, StatEffect};
+use crate::data::userfile::UserValues;
+
+/// fraction of the regular bestiary that must be seen since the last prestige before prestiging again
+const PRESTIGE_BESTIARY_FRACTION: f32 = 0.75;
+
+const VALUE_BONUS_PER_PRESTIGE: f32 = 0.1;
+const CATCH_CHANCE_PER_PRESTIGE: f32 = 0.01;
+const DISCOUNT_PER_PRESTIGE: f32 = 0.05;
+const MAX_DISCOUNT: f32 = 0.5;
+
+/// Adds the permanent prestige multipliers to the angler's bonuses
+pub fn apply_prestige(bonuses: &mut AnglerBonuses, prestige: u32) {
+    let prestige_f = prestige as f32;
+
+    bonuses.prestige = prestige;
+    bonuses.value_multiplier += VALUE_BONUS_PER_PRESTIGE * prestige_f;
+    if prestige > 0 {
+        bonuses.stat_effects.push(StatEffect::add(format!("Prestige {}", prestige), Stat::CatchChance, CATCH_CHANCE_PER_PRESTIGE * prestige_f));
+    }
+    bonuses.shop_discount = (bonuses.shop_discount + DISCOUNT_PER_PRESTIGE * prestige_f).min(MAX_DISCOUNT);
+}
+
+/// Applies a shop discount to a price
+pub fn discounted(cost: Money, discount: f32) -> Money {
+    cost.scale(1.0 - discount)
+}
+
+/// Describes what is still needed before the player can prestige, or `None` if they can
+pub fn prestige_requirements(userfile: &UserValues, fish_data: &FishData, rarity: &RodRarity) -> Option<String> {
+    let seen = fish_data.regular_seen(&userfile.seen_this_prestige);
+    let needed = (fish_data.regular_fish().count() as f32 * PRESTIGE_BESTIARY_FRACTION).ceil() as usize;
+
+    let mut missing = Vec::new();
+    // starter, prestige and event rods don't count, only the shop's best
+    if !matches!(rarity, RodRarity::Epic) {
+        missing.push("Own an Epic rod".to_string());
+    }
+    if seen < needed {
+        missing.push(format!("See {} more regular fish this prestige ({}/{})", needed - seen, seen, needed));
+    }
+
+    if missing.is_empty() {
+        None
+    } else {
+        Some(missing.join("\n"))
+    }
+}
+
+/// Resets the player's money, rod and prestige progress in exchange for a prestige level
+pub fn prestige(userfile: &mut UserValues) {
+    let default = UserValues::default();
+
+    userfile.prestige += 1;
+    userfile.seen_this_prestige = default.seen_this_prestige;
+    userfile.money = default.money;
+    userfile.rod_id = default.rod_id;
+    userfile.rod_modifiers = default.rod_modifiers;
+    userfile.crafted_rod = default.crafted_rod;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A player who has seen every regular fish since their last prestige
+    fn ready(prestige: u32, fish_data: &FishData) -> UserValues {
+        let seen: Vec<String> = fish_data.fish.iter().filter(|f| f.prestige == 0).map(|f| f.id.clone()).collect();
+        UserValues {
+            prestige,
+            has_seen: seen.clone(),
+            seen_this_prestige: seen,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn only_epic_rods_count() {
+        let fish_data = crate::data::fish_data();
+        let userfile = ready(0, &fish_data);
+
+        assert!(prestige_requirements(&userfile, &fish_data, &RodRarity::Epic).is_none());
+        for rarity in [RodRarity::Common, RodRarity::Uncommon, RodRarity::Rare, RodRarity::Unobtainable, RodRarity::Event] {
+            assert!(prestige_requirements(&userfile, &fish_data, &rarity).is_some());
+        }
+    }
+
+    #[test]
+    fn each_prestige_needs_new_progress() {
+        let fish_data = crate::data::fish_data();
+        let mut userfile = ready(0, &fish_data);
+
+        prestige(&mut userfile);
+        assert_eq!(userfile.prestige, 1);
+        assert!(prestige_requirements(&userfile, &fish_data, &RodRarity::Epic).is_some());
+
+        // the bestiary is kept, but doesn't count towards the next prestige
+        assert!(!userfile.has_seen.is_empty());
+        userfile.seen_this_prestige = ready(1, &fish_data).seen_this_prestige;
+        assert!(prestige_requirements(&userfile, &fish_data, &RodRarity::Epic).is_none());
+    }
+}
@@ -0,0 +1,238 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDateTime};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::data::fish::{Fish, FishData, FishRarity};
+use crate::data::money::Money;
+use crate::data::rods::{Rod, RodData};
+use crate::data::shop::next_midnight;
+
+const DAILY_QUESTS: usize = 3;
+const WEEKLY_QUESTS: usize = 2;
+
+/// Midnight on the Monday after `now`
+pub fn next_week(now: DateTime<Local>) -> NaiveDateTime {
+    let days_until_monday = 7 - now.weekday().num_days_from_monday() as i64;
+
+    (now + Duration::days(days_until_monday)).date_naive().and_hms_opt(0, 0, 0).unwrap()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum QuestKind {
+    Daily,
+    Weekly,
+}
+
+impl QuestKind {
+    /// how much harder (and more rewarding) the quest is
+    fn scale(&self) -> u32 {
+        match self {
+            QuestKind::Daily => 1,
+            QuestKind::Weekly => 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum QuestObjective {
+    CatchFish(u32),
+    CatchRarity { rarity: FishRarity, count: u32 },
+    CatchSpecies { species: String, min_weight: u32 },
+    EarnMoney(u32),
+}
+
+impl QuestObjective {
+    pub fn goal(&self) -> u32 {
+        match self {
+            QuestObjective::CatchFish(count) => *count,
+            QuestObjective::CatchRarity { count, .. } => *count,
+            QuestObjective::CatchSpecies { .. } => 1,
+            QuestObjective::EarnMoney(amount) => *amount,
+        }
+    }
+
+    /// How much progress this catch adds towards the objective
+    pub fn progress_for(&self, fish: &Fish, value: Money) -> u32 {
+        match self {
+            QuestObjective::CatchFish(_) => 1,
+            QuestObjective::CatchRarity { rarity, .. } => (fish.rarity.ident() >= rarity.ident()) as u32,
+            QuestObjective::CatchSpecies { species, min_weight } =>
+                (&fish.fish_type.id == species && fish.weight >= *min_weight as f32) as u32,
+            QuestObjective::EarnMoney(_) => u32::try_from(value.dollars()).unwrap_or(u32::MAX),
+        }
+    }
+
+    pub fn describe(&self, fish_data: &FishData) -> String {
+        match self {
+            QuestObjective::CatchFish(count) => format!("Catch {} fish", count),
+            QuestObjective::CatchRarity { rarity, count } => format!("Catch {} {} (or rarer) fish", count, rarity),
+            QuestObjective::CatchSpecies { species, min_weight } =>
+                format!("Catch a {} over {}lbs", fish_data.species_name(species), min_weight),
+            QuestObjective::EarnMoney(amount) => format!("Earn {} from fishing", Money::new(u64::from(*amount))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum QuestReward {
+    Money(Money),
+    /// the id of a modifier applied to the player's rod
+    Modifier(String),
+}
+
+impl QuestReward {
+    pub fn describe(&self, rod_data: &RodData) -> String {
+        match self {
+            QuestReward::Money(amount) => amount.to_string(),
+            QuestReward::Modifier(id) => format!("{} rod modifier", rod_data.modifier_name(id)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Quest {
+    pub kind: QuestKind,
+    pub objective: QuestObjective,
+    pub progress: u32,
+    pub reward: QuestReward,
+    pub completed: bool,
+}
+
+impl Quest {
+    pub fn generate(kind: QuestKind, fish_data: &FishData, rod_data: &RodData, rod: &Rod) -> Self {
+        let mut rng = rand::thread_rng();
+        let scale = kind.scale();
+
+        // only ask for species the player can reach, and that don't leave with a location or event before the quest does
+        let species: Vec<_> = fish_data.get_fish_above_depth(rod.get_depth(), &rod.location.id).into_iter()
+            .filter(|f| f.location.is_none() && f.event.is_none() && f.prestige == 0)
+            .filter(|f| f.min_rarity.ident() <= FishRarity::Rare.ident() && f.max_weight > f.avg_weight)
+            .collect();
+
+        let (objective, money) = match rng.gen_range(0..4) {
+            0 => (QuestObjective::CatchFish(20 * scale), 250 * scale),
+            1 => {
+                let (rarity, count) = match rng.gen_range(0..3) {
+                    0 => (FishRarity::Uncommon, 10),
+                    1 => (FishRarity::Rare, 5),
+                    _ => (FishRarity::Elusive, 1),
+                };
+                let money = 100 * count * (rarity.ident() as u32 + 1);
+                (QuestObjective::CatchRarity { rarity, count: count * scale }, money * scale)
+            }
+            2 if !species.is_empty() => {
+                let fish = species[rng.gen_range(0..species.len())];
+                let objective = QuestObjective::CatchSpecies {
+                    species: fish.id.clone(),
+                    min_weight: fish.avg_weight + (fish.max_weight - fish.avg_weight) * (scale - 1) / 10,
+                };
+                (objective, (fish.value * 5).max(200) * scale)
+            }
+            _ => (QuestObjective::EarnMoney(1000 * scale), 300 * scale),
+        };
+
+        // weekly quests always hand out a modifier, daily quests sometimes do
+        let reward = if kind == QuestKind::Weekly || rng.gen_range(0..100) < 15 {
+            QuestReward::Modifier(rod_data.roll_reward_modifier().id)
+        } else {
+            QuestReward::Money(Money::new(u64::from(money)))
+        };
+
+        Self {
+            kind,
+            objective,
+            progress: 0,
+            reward,
+            completed: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct QuestBoard {
+    pub daily_refresh: i64,
+    pub weekly_refresh: i64,
+    pub quests: Vec<Quest>,
+}
+
+impl QuestBoard {
+    /// Replaces the daily and weekly quests that expired by `now`, returns true if anything changed
+    pub fn refresh(&mut self, fish_data: &FishData, rod_data: &RodData, rod: &Rod, now: DateTime<Local>) -> bool {
+        let mut changed = false;
+
+        if now.timestamp() >= self.daily_refresh {
+            self.quests.retain(|q| q.kind != QuestKind::Daily);
+            for _ in 0..DAILY_QUESTS {
+                self.quests.push(Quest::generate(QuestKind::Daily, fish_data, rod_data, rod));
+            }
+            self.daily_refresh = next_midnight(now).and_local_timezone(Local).unwrap().timestamp();
+            changed = true;
+        }
+
+        if now.timestamp() >= self.weekly_refresh {
+            self.quests.retain(|q| q.kind != QuestKind::Weekly);
+            for _ in 0..WEEKLY_QUESTS {
+                self.quests.push(Quest::generate(QuestKind::Weekly, fish_data, rod_data, rod));
+            }
+            self.weekly_refresh = next_week(now).and_local_timezone(Local).unwrap().timestamp();
+            changed = true;
+        }
+
+        changed
+    }
+
+    pub fn get_time_until_reset(&self, kind: QuestKind) -> String {
+        let reset = match kind {
+            QuestKind::Daily => self.daily_refresh,
+            QuestKind::Weekly => self.weekly_refresh,
+        };
+        let duration = Duration::seconds((reset - Local::now().timestamp()).max(0));
+
+        let days = duration.num_days();
+        let hours = duration.num_hours() - (days * 24);
+        let minutes = duration.num_minutes() - (duration.num_hours() * 60);
+
+        if days > 0 {
+            format!("{} days, {} hours, {} minutes", days, hours, minutes)
+        } else {
+            format!("{} hours, {} minutes", hours, minutes)
+        }
+    }
+
+    /// Adds progress for a caught fish and returns the quests that were just completed
+    pub fn record_catch(&mut self, fish: &Fish, value: Money) -> Vec<Quest> {
+        let mut completed = Vec::new();
+
+        for quest in self.quests.iter_mut().filter(|q| !q.completed) {
+            quest.progress = quest.progress.saturating_add(quest.objective.progress_for(fish, value)).min(quest.objective.goal());
+            if quest.progress >= quest.objective.goal() {
+                quest.completed = true;
+                completed.push(quest.clone());
+            }
+        }
+
+        completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::userfile::UserValues;
+
+    #[test]
+    fn species_quests_stay_catchable() {
+        let fish_data = crate::data::fish_data();
+        let rod_data = crate::data::rod_data();
+        let boat_data = crate::data::boat_data();
+        let mut rod = UserValues { rod_id: "graphite_fly_rod".to_string(), ..Default::default() }
+            .get_rod(&rod_data, &crate::data::component_data(), &crate::data::tackle_data(), &boat_data, &crate::data::skill_data());
+        rod.location = boat_data.get_location("coastal_waters").unwrap().clone();
+
+        for _ in 0..500 {
+            if let QuestObjective::CatchSpecies { species, .. } = Quest::generate(QuestKind::Weekly, &fish_data, &rod_data, &rod).objective {
+                let fish = fish_data.get_fish(&species).unwrap();
+                assert!(fish.location.is_none() && fish.event.is_none() && fish.prestige == 0, "{}", species);
+            }
+        }
+    }
+}
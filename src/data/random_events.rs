@@ -0,0 +1,122 @@
+use egui::ImageSource;
+use rand::Rng;
+use crate::data::fish::Fish;
+use crate::data::history::CatchOutcome;
+use crate::data::money::Money;
+use crate::data::rods::Rod;
+use crate::data::shop::RodRarity;
+use crate::data::userfile::UserValues;
+
+#[derive(Debug, Clone)]
+pub enum EventCondition {
+    /// only while fishing at the location with this id
+    Location(String),
+    /// the rod reaches at least this many feet down
+    MinDepth(u32),
+    /// the fish weighs at least this many lbs
+    MinWeight(u32),
+}
+
+impl EventCondition {
+    pub fn is_met(&self, fish: &Fish, rod: &Rod) -> bool {
+        match self {
+            EventCondition::Location(id) => &rod.location.id == id,
+            EventCondition::MinDepth(depth) => rod.get_depth() >= *depth,
+            EventCondition::MinWeight(weight) => fish.weight >= *weight as f32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum EventEffect {
+    /// something makes off with the fish
+    Steal,
+    /// the fish gets away
+    Escape,
+    /// multiplies the catch's value
+    ValueMultiplier(f32),
+    /// money on top of the catch
+    Money(Money),
+    /// a random rod component of this rarity
+    Component(RodRarity),
+    /// angler experience
+    Xp(u32),
+}
+
+/// Something that can happen when a fish is about to be landed
+#[derive(Debug, Clone)]
+pub struct RandomEvent {
+    pub id: String,
+    /// chance out of 1000 per catch
+    pub chance: f32,
+    /// whether the Turtle Repellent skill makes this less likely
+    pub repellable: bool,
+    pub conditions: Vec<EventCondition>,
+    pub effects: Vec<EventEffect>,
+    /// shown when the event happens, `{fish}` is replaced with the fish
+    pub text: String,
+    /// shown in place of the rod while the event's text is up
+    pub image: Option<ImageSource<'static>>,
+}
+
+impl RandomEvent {
+    /// The outcome of the cast if this event costs the player the fish
+    pub fn lost_outcome(&self) -> Option<CatchOutcome> {
+        self.effects.iter().find_map(|effect| match effect {
+            EventEffect::Steal => Some(CatchOutcome::Stolen),
+            EventEffect::Escape => Some(CatchOutcome::Escaped),
+            _ => None,
+        })
+    }
+
+    pub fn value_multiplier(&self) -> f32 {
+        self.effects.iter()
+            .filter_map(|effect| match effect {
+                EventEffect::ValueMultiplier(multiplier) => Some(*multiplier),
+                _ => None,
+            })
+            .product()
+    }
+
+    /// Hands out the event's rewards, the catch itself is handled by the caller
+    pub fn apply(&self, userfile: &mut UserValues) {
+        for effect in &self.effects {
+            match effect {
+                EventEffect::Money(amount) => userfile.money += *amount,
+                EventEffect::Component(rarity) => {
+                    let component = crate::data::component_data().generate_component(rarity.clone());
+                    *userfile.components.entry(component.name).or_insert(0) += 1;
+                }
+                EventEffect::Xp(amount) => userfile.xp += amount,
+                EventEffect::Steal | EventEffect::Escape | EventEffect::ValueMultiplier(_) => {}
+            }
+        }
+    }
+
+    pub fn describe(&self, fish: &Fish) -> String {
+        self.text.replace("{fish}", &format!("{}lb {}", fish.weight, fish))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RandomEventData {
+    pub events: Vec<RandomEvent>,
+}
+
+impl RandomEventData {
+    /// Rolls each event that can happen for this catch, returning the first one that does
+    pub fn roll(&self, fish: &Fish, rod: &Rod) -> Option<&RandomEvent> {
+        let mut rng = rand::thread_rng();
+
+        self.events.iter()
+            .filter(|event| event.conditions.iter().all(|c| c.is_met(fish, rod)))
+            .find(|event| {
+                let chance = if event.repellable {
+                    event.chance * rod.bonuses.turtle_chance_multiplier
+                } else {
+                    event.chance
+                };
+                (rng.gen_range(0..1000) as f32) < chance
+            })
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use crate::data::fish::{Fish, FishRarity};
+use crate::data::money::Money;
+
+/// A player's personal records for a single species
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SpeciesRecord {
+    pub caught: u32,
+    pub lost: u32,
+    pub heaviest: f32,
+    pub lightest: f32,
+    pub highest_rarity: Option<FishRarity>,
+    pub best_value: Money,
+    /// timestamp of the first catch
+    pub first_caught: Option<i64>,
+    /// every rarity this species has been caught at
+    #[serde(default)]
+    pub rarities: Vec<FishRarity>,
+}
+
+impl SpeciesRecord {
+    pub fn record_catch(&mut self, fish: &Fish, value: Money, now: i64) {
+        if self.caught == 0 {
+            self.heaviest = fish.weight;
+            self.lightest = fish.weight;
+            self.first_caught = Some(now);
+        } else {
+            self.heaviest = self.heaviest.max(fish.weight);
+            self.lightest = self.lightest.min(fish.weight);
+        }
+        self.caught += 1;
+
+        let is_rarer = self.highest_rarity.as_ref().is_none_or(|r| fish.rarity.ident() > r.ident());
+        if is_rarer {
+            self.highest_rarity = Some(fish.rarity.clone());
+        }
+
+        if !self.rarities.iter().any(|r| r.ident() == fish.rarity.ident()) {
+            self.rarities.push(fish.rarity.clone());
+        }
+
+        self.best_value = self.best_value.max(value);
+    }
+
+    /// Records a fish that escaped, broke the line or was stolen
+    pub fn record_loss(&mut self) {
+        self.lost += 1;
+    }
+}
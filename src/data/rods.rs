@@ -1,64 +1,226 @@
 use std::fmt::{Display, Formatter};
 use rand::Rng;
+use crate::data::boats::{Boat, Location};
+use crate::data::catalog::{Catalog, Identified};
+use crate::data::components::CRAFTED_ROD_ID;
+use crate::data::locale::localized;
+use crate::data::money::Money;
 use crate::data::shop::RodRarity;
+use crate::data::skills::AnglerBonuses;
+use crate::data::stat_pipeline::{Stat, StatEffect, StatPipeline};
+use crate::data::tackle::{EquippedTackle, TackleSlot};
+
+/// the most modifiers a single rod can have
+pub const MAX_MODIFIERS: usize = 3;
 
 // https://docs.google.com/spreadsheets/d/1k_U3l-JPknjTrtXBf2-Y2J1kPrGCJLQs5KjHkO0jQ_E/edit?usp=sharing
 
 #[derive(Debug, Clone)]
 pub struct BaseRod {
+    pub id: String,
     pub name: String,
     pub description: String,
     pub catch_chance: f32,
     pub catch_rate: f32,
     pub depth: u32,
     pub weight_limit: u32,
-    pub cost: f32,
+    pub cost: Money,
     pub rarity: RodRarity,
+    /// minimum prestige level needed to buy this rod, 0 for regular rods
+    pub prestige: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum ModifierTier {
+    Common, // 50%
+    Uncommon, // 30%
+    Rare, // 14%
+    Epic, // 5%
+    Legendary, // 1%
+    /// never rolled, only handed out as collection rewards
+    Exclusive,
+}
+
+impl ModifierTier {
+    pub fn all() -> [Self; 5] {
+        [Self::Common, Self::Uncommon, Self::Rare, Self::Epic, Self::Legendary]
+    }
+
+    pub fn get_weight(&self) -> u16 {
+        match self {
+            ModifierTier::Common => 500,
+            ModifierTier::Uncommon => 300,
+            ModifierTier::Rare => 140,
+            ModifierTier::Epic => 50,
+            ModifierTier::Legendary => 10,
+            ModifierTier::Exclusive => 0,
+        }
+    }
+
+    pub fn ident(&self) -> u8 {
+        match self {
+            ModifierTier::Common => 0,
+            ModifierTier::Uncommon => 1,
+            ModifierTier::Rare => 2,
+            ModifierTier::Epic => 3,
+            ModifierTier::Legendary => 4,
+            ModifierTier::Exclusive => 5,
+        }
+    }
+
+    pub fn weighted_random() -> Self {
+        let mut rng = rand::thread_rng();
+
+        let total: u16 = Self::all().iter().map(|t| t.get_weight()).sum();
+        let mut num = rng.gen_range(1..=total);
+
+        for tier in Self::all() {
+            if num <= tier.get_weight() {
+                return tier;
+            }
+            num -= tier.get_weight();
+        }
+        Self::Common
+    }
+}
+
+impl Display for ModifierTier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModifierTier::Common => write!(f, "Common"),
+            ModifierTier::Uncommon => write!(f, "Uncommon"),
+            ModifierTier::Rare => write!(f, "Rare"),
+            ModifierTier::Epic => write!(f, "Epic"),
+            ModifierTier::Legendary => write!(f, "Legendary"),
+            ModifierTier::Exclusive => write!(f, "Exclusive"),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct RodModifier {
+    pub id: String,
     pub name: String,
+    pub tier: ModifierTier,
     pub catch_chance: f32,
     pub catch_rate: f32,
     pub depth: i32,
     pub weight_limit: i32,
 }
 
+impl RodModifier {
+    /// Drawbacks shrink how deep or how heavy the rod can fish, they're never handed out as rewards
+    pub fn is_drawback(&self) -> bool {
+        self.depth < 0 || self.weight_limit < 0
+    }
+}
+
 impl Display for RodModifier {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.name)
+        write!(f, "{}", localized(&self.id, &self.name))
+    }
+}
+
+impl Identified for RodModifier {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for BaseRod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", localized(&self.id, &self.name))
+    }
+}
+
+impl Identified for BaseRod {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct RodData {
-    pub rods: Vec<BaseRod>,
-    pub modifiers: Vec<RodModifier>
+    pub rods: Catalog<BaseRod>,
+    pub modifiers: Catalog<RodModifier>,
 }
 
 impl RodData {
 
-    pub fn get_base_by_name<S: Into<String>>(&self, name: S) -> Option<BaseRod> {
-        let name = name.into();
-        self.rods.clone().into_iter().find(|rod| rod.name == name)
+    pub fn get_base(&self, id: &str) -> Option<&BaseRod> {
+        self.rods.get(id)
+    }
+
+    pub fn get_modifier(&self, id: &str) -> Option<&RodModifier> {
+        self.modifiers.get(id)
+    }
+
+    /// The display name of a modifier id, or the id itself if the modifier no longer exists
+    pub fn modifier_name(&self, id: &str) -> String {
+        self.modifiers.get(id).map_or(id.to_string(), |modifier| modifier.to_string())
+    }
+
+    /// The display name of a base rod id, or the id itself if the rod no longer exists
+    pub fn rod_name(&self, id: &str) -> String {
+        match self.rods.get(id) {
+            Some(rod) => rod.to_string(),
+            None if id == CRAFTED_ROD_ID => "Crafted Rod".to_string(),
+            None => id.to_string(),
+        }
+    }
+
+    /// Rolls up to `MAX_MODIFIERS` distinct modifiers. The first is rolled with `chance`
+    /// (0.0 to 1.0), and every extra modifier is half as likely as the one before it.
+    /// Each modifier has a `luck` (0.0 to 1.0) chance of being rolled without drawbacks.
+    pub fn roll_modifiers(&self, chance: f32, luck: f32) -> Vec<RodModifier> {
+        let mut rng = rand::thread_rng();
+        let mut modifiers: Vec<RodModifier> = Vec::new();
+        let mut chance = chance;
+
+        while modifiers.len() < MAX_MODIFIERS && rng.gen_range(0.0..1.0) < chance {
+            let modifier = if rng.gen_range(0.0..1.0) < luck {
+                self.roll_reward_modifier()
+            } else {
+                self.roll_modifier()
+            };
+            if modifiers.iter().any(|m| m.id == modifier.id) {
+                continue;
+            }
+            modifiers.push(modifier);
+            chance /= 2.0;
+        }
+
+        modifiers
     }
 
-    pub fn get_modifier_by_name<S: Into<String>>(&self, name: S) -> Option<RodModifier> {
-        let name = name.into();
-        self.modifiers.clone().into_iter().find(|modifier| modifier.name == name)
+    /// Rolls a modifier tier by weight, then picks a random modifier from that tier.
+    pub fn roll_modifier(&self) -> RodModifier {
+        self.roll_modifier_where(|_| true)
     }
 
-    pub fn generate_modifier(&self) -> Option<RodModifier> {
+    /// Rolls a modifier like `roll_modifier`, but never a drawback
+    pub fn roll_reward_modifier(&self) -> RodModifier {
+        self.roll_modifier_where(|m| !m.is_drawback())
+    }
+
+    fn roll_modifier_where(&self, allowed: impl Fn(&RodModifier) -> bool) -> RodModifier {
         let mut rng = rand::thread_rng();
-        let chance = rng.gen_range(0..100);
+        let tier = ModifierTier::weighted_random();
 
-        if chance < 10 {
-            let index = rng.gen_range(0..self.modifiers.len());
-            Some(self.modifiers[index].clone())
-        } else {
-            None
+        let mut modifiers: Vec<&RodModifier> = self.modifiers.iter().filter(|m| m.tier.ident() == tier.ident() && allowed(m)).collect();
+        if modifiers.is_empty() {
+            modifiers = self.modifiers.iter().filter(|m| !matches!(m.tier, ModifierTier::Exclusive) && allowed(m)).collect();
         }
+
+        modifiers[rng.gen_range(0..modifiers.len())].clone()
     }
 
     pub fn generate_rod_base(&self, rarity: RodRarity) -> BaseRod {
@@ -66,7 +228,7 @@ impl RodData {
 
         let mut rods: Vec<&BaseRod> = self.rods.iter().filter(|r| r.rarity.get_ident() == rarity.get_ident()).collect();
         if rods.is_empty() {
-            rods.push(self.rods.get(rng.gen_range(0..self.rods.len())).unwrap());
+            rods.push(&self.rods[rng.gen_range(0..self.rods.len())]);
         }
 
         rods[rng.gen_range(0..rods.len())].clone()
@@ -76,44 +238,78 @@ impl RodData {
 #[derive(Debug, Clone)]
 pub struct Rod {
     pub base: BaseRod,
-    pub modifier: Option<RodModifier>,
+    pub modifiers: Vec<RodModifier>,
+    pub bonuses: AnglerBonuses,
+    pub tackle: Vec<EquippedTackle>,
+    /// the angler's boat, if it's not docked
+    pub boat: Option<Boat>,
+    /// where the rod is being cast
+    pub location: Location,
 }
 
 impl Rod {
-    pub fn get_catch_rate(&self) -> u32 {
-        let mut catch_rate = self.base.catch_rate;
-        if let Some(m) = self.modifier.clone() {
-            catch_rate += m.catch_rate;
+    /// Collects every effect on the rod's stats: the base rod, its modifiers, tackle, boat,
+    /// then the angler's skills and prestige
+    pub fn pipeline(&self) -> StatPipeline {
+        let mut pipeline = StatPipeline::default();
+
+        let base = self.base.to_string();
+        pipeline.push(StatEffect::add(base.as_str(), Stat::CatchRate, self.base.catch_rate));
+        pipeline.push(StatEffect::add(base.as_str(), Stat::CatchChance, self.base.catch_chance));
+        pipeline.push(StatEffect::add(base.as_str(), Stat::Depth, self.base.depth as f32));
+        pipeline.push(StatEffect::add(base.as_str(), Stat::WeightLimit, self.base.weight_limit as f32));
+
+        for m in &self.modifiers {
+            let effects = [
+                (Stat::CatchRate, m.catch_rate),
+                (Stat::CatchChance, m.catch_chance),
+                (Stat::Depth, m.depth as f32),
+                (Stat::WeightLimit, m.weight_limit as f32),
+            ];
+            pipeline.extend(effects.into_iter()
+                .filter(|(_, amount)| *amount != 0.0)
+                .map(|(stat, amount)| StatEffect::add(format!("{} modifier", m), stat, amount)));
         }
 
-        (catch_rate as u32).max(2)
-    }
+        for t in &self.tackle {
+            let effect = match t.tackle.slot {
+                TackleSlot::Line => StatEffect::add(t.to_string(), Stat::WeightLimit, t.bonus()),
+                TackleSlot::Reel => StatEffect::add(t.to_string(), Stat::CatchRate, -t.bonus()),
+                TackleSlot::Hook => StatEffect::add(t.to_string(), Stat::CatchChance, t.bonus()),
+                TackleSlot::Sinker => StatEffect::add(t.to_string(), Stat::Depth, t.bonus()),
+            };
+            pipeline.push(effect);
+        }
 
-    pub fn get_catch_chance(&self) -> u32 {
-        let mut catch_chance = self.base.catch_chance;
-        if let Some(m) = self.modifier.clone() {
-            catch_chance += m.catch_chance;
+        if let Some(boat) = &self.boat {
+            pipeline.push(StatEffect::add(boat.to_string(), Stat::Depth, boat.depth as f32));
         }
 
-        (catch_chance * 1000.0).round() as u32
+        pipeline.extend(self.bonuses.stat_effects.iter().cloned());
+
+        pipeline
     }
 
-    pub fn get_depth(&self) -> u32 {
-        let mut depth = self.base.depth as i32;
-        if let Some(m) = self.modifier.clone() {
-            depth += m.depth;
-        }
+    /// Explains where each of the rod's final stats came from
+    pub fn breakdown(&self) -> String {
+        let pipeline = self.pipeline();
+        Stat::all().iter().map(|stat| pipeline.breakdown(*stat)).collect::<Vec<_>>().join("\n")
+    }
 
-        depth as u32
+    pub fn get_catch_rate(&self) -> u32 {
+        self.pipeline().compute(Stat::CatchRate) as u32
     }
 
-    pub fn get_weight_limit(&self) -> u32 {
-        let mut weight_limit = self.base.weight_limit as i32;
-        if let Some(m) = self.modifier.clone() {
-            weight_limit += m.weight_limit;
-        }
+    pub fn get_catch_chance(&self) -> u32 {
+        (self.pipeline().compute(Stat::CatchChance) * 1000.0).round() as u32
+    }
 
-        weight_limit as u32
+    pub fn get_depth(&self) -> u32 {
+        self.pipeline().compute(Stat::Depth) as u32
+    }
+
+    pub fn get_weight_limit(&self) -> u32 {
+        self.pipeline().compute(Stat::WeightLimit) as u32
     }
 
     pub fn random_catch_time(&self) -> f32 {
@@ -128,8 +324,9 @@ impl Rod {
 
 impl Display for Rod {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}",
-               if let Some(m) = self.modifier.clone() { format!("{} ", m) }
-        else { "".to_string() }, self.base.name)
+        for m in &self.modifiers {
+            write!(f, "{} ", m)?;
+        }
+        write!(f, "{}", self.base)
     }
-}
\ No newline at end of file
+}
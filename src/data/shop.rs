@@ -1,12 +1,41 @@
 use std::fmt::Display;
-use chrono::{Duration, Local, NaiveDateTime};
+use chrono::{DateTime, Duration, Local, NaiveDateTime};
 use serde::{Deserialize, Serialize};
-use crate::data::rods::{Rod, RodData};
-use crate::say;
+use crate::data::achievements::AchievementEvent;
+use crate::data::boats::BoatData;
+use crate::data::components::ComponentData;
+use crate::data::events::FishingEvent;
+use crate::data::money::Money;
+use crate::data::prestige::discounted;
+use crate::data::rods::{BaseRod, Rod, RodData, RodModifier};
+use crate::data::tackle::{OwnedTackle, TackleData};
+use crate::data::userfile::UserValues;
+use crate::{say, yay};
+
+pub const AUTO_FISHER_COST: Money = Money::new(5000);
+/// chance of a bought rod rolling its first modifier, before skills
+const BASE_MODIFIER_CHANCE: f32 = 0.1;
+
+/// Lists modifiers with their tiers, e.g. "Sharp (Uncommon), Swift (Rare)"
+fn describe_modifiers(modifiers: &[RodModifier]) -> String {
+    modifiers.iter().map(|m| format!("{} ({})", m, m.tier)).collect::<Vec<_>>().join(", ")
+}
+
+/// The player's shop discount from their skills and prestige
+fn shop_discount(user_file: &UserValues) -> f32 {
+    user_file.get_bonuses(&crate::data::skill_data()).shop_discount
+}
 
-fn next_midnight() -> NaiveDateTime {
-    let now = Local::now();
+/// Takes a rod's regular (discounted) price from the player's money
+fn pay_money(user_file: &mut UserValues, base_rod: &BaseRod) -> Result<(), BuyError> {
+    let cost = discounted(base_rod.cost, shop_discount(user_file));
 
+    // ensure the user has enough money to buy the rod
+    user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+    Ok(())
+}
+
+pub(crate) fn next_midnight(now: DateTime<Local>) -> NaiveDateTime {
     (now + Duration::days(1)).date_naive().and_hms_opt(0, 0, 0).unwrap()
 }
 
@@ -17,6 +46,8 @@ pub enum RodRarity {
     Rare, // 20%
     Epic, // 10%
     Unobtainable, // 0%
+    /// only sold by a running event, for its currency
+    Event,
 }
 
 impl RodRarity {
@@ -27,6 +58,21 @@ impl RodRarity {
             RodRarity::Rare => 2,
             RodRarity::Epic => 3,
             RodRarity::Unobtainable => 4,
+            RodRarity::Event => 5,
+        }
+    }
+
+    /// The price of rerolling the modifier on a rod of this rarity, `None` for rods that can't be reforged
+    pub fn reforge_cost(&self) -> Option<Money> {
+        match self {
+            RodRarity::Common => Some(Money::new(100)),
+            RodRarity::Uncommon => Some(Money::new(250)),
+            RodRarity::Rare => Some(Money::new(750)),
+            RodRarity::Epic => Some(Money::new(1500)),
+            // starter and prestige rods aren't sold in the shop, so it won't rework them
+            RodRarity::Unobtainable => None,
+            // event rods are made for the event and go back to it for anything else
+            RodRarity::Event => None,
         }
     }
 }
@@ -39,6 +85,7 @@ impl Display for RodRarity {
             RodRarity::Rare => write!(f, "Rare"),
             RodRarity::Epic => write!(f, "Epic"),
             RodRarity::Unobtainable => write!(f, "Unobtainable"),
+            RodRarity::Event => write!(f, "Event"),
         }
     }
 }
@@ -47,6 +94,10 @@ impl Display for RodRarity {
 pub enum BuyError {
     NoMoney,
     InvalidRod,
+    AlreadyOwned,
+    InvalidItem,
+    MaxLevel,
+    NotReforgeable,
 }
 
 impl Display for BuyError {
@@ -54,6 +105,10 @@ impl Display for BuyError {
         match self {
             BuyError::NoMoney => write!(f, "You don't have enough money to buy this rod!"),
             BuyError::InvalidRod => write!(f, "That rod is no longer available!"),
+            BuyError::AlreadyOwned => write!(f, "You already own this!"),
+            BuyError::InvalidItem => write!(f, "That item is no longer available!"),
+            BuyError::MaxLevel => write!(f, "That is already fully upgraded!"),
+            BuyError::NotReforgeable => write!(f, "The shop can't reforge this rod!"),
         }
     }
 }
@@ -62,6 +117,8 @@ impl Display for BuyError {
 pub struct Shop {
     pub refresh: i64,
     pub rods: Vec<String>,
+    #[serde(default)]
+    pub components: Vec<String>,
     // todo: bait goes here
 }
 
@@ -70,20 +127,28 @@ impl Shop {
     pub fn create(rod_data: &RodData) -> Shop {
         // create a shop
         let mut shop = Self {
-            refresh: next_midnight().timestamp(),
+            refresh: next_midnight(Local::now()).timestamp(),
             rods: Vec::new(),
+            components: Vec::new(),
         };
 
         // generate daily rods rods and add them to the shop
-        shop.rods.push(rod_data.generate_rod_base(RodRarity::Common).name);
-        shop.rods.push(rod_data.generate_rod_base(RodRarity::Common).name);
+        shop.rods.push(rod_data.generate_rod_base(RodRarity::Common).id);
+        shop.rods.push(rod_data.generate_rod_base(RodRarity::Common).id);
 
-        shop.rods.push(rod_data.generate_rod_base(RodRarity::Uncommon).name);
-        shop.rods.push(rod_data.generate_rod_base(RodRarity::Uncommon).name);
+        shop.rods.push(rod_data.generate_rod_base(RodRarity::Uncommon).id);
+        shop.rods.push(rod_data.generate_rod_base(RodRarity::Uncommon).id);
 
-        shop.rods.push(rod_data.generate_rod_base(RodRarity::Rare).name);
+        shop.rods.push(rod_data.generate_rod_base(RodRarity::Rare).id);
 
-        shop.rods.push(rod_data.generate_rod_base(RodRarity::Epic).name);
+        shop.rods.push(rod_data.generate_rod_base(RodRarity::Epic).id);
+
+        // generate daily rod components
+        let component_data = crate::data::component_data();
+        shop.components.push(component_data.generate_component(RodRarity::Common).name);
+        shop.components.push(component_data.generate_component(RodRarity::Common).name);
+        shop.components.push(component_data.generate_component(RodRarity::Uncommon).name);
+        shop.components.push(component_data.generate_component(RodRarity::Rare).name);
 
         // write the shop to a file
         let serialized = serde_json::to_string(&shop).unwrap();
@@ -104,7 +169,7 @@ impl Shop {
     pub fn get_time_until_restock(&self) -> String {
         // from now to next midnight
         let now = Local::now();
-        let next_midnight = next_midnight();
+        let next_midnight = next_midnight(now);
 
         let duration = next_midnight - now.naive_local();
 
@@ -137,12 +202,16 @@ impl Shop {
         let contents = std::fs::read_to_string(path).unwrap();
 
         let mut shop: Shop = serde_json::from_str(contents.as_str()).unwrap();
+        // shops saved before rods had ids list them by name
+        for rod in &mut shop.rods {
+            rod_data.rods.migrate(rod);
+        }
 
         // check if refresh is needed
         if shop.should_refresh() {
             say!("Refreshing shop");
             let mut new_shop = Self::create(rod_data);
-            new_shop.refresh = next_midnight().timestamp();
+            new_shop.refresh = next_midnight(Local::now()).timestamp();
             shop = new_shop;
         }
 
@@ -154,50 +223,251 @@ impl Shop {
             return Err(BuyError::InvalidRod);
         }
 
-        let base_rod_name = self.rods.get(spot).unwrap().clone();
-        let base_rod = rod_data.get_base_by_name(base_rod_name).unwrap();
+        let base_rod = rod_data.get_base(&self.rods[spot]).unwrap().clone();
 
-        let mut user_file = crate::data::userfile::read_userfile();
+        Self::buy_base_rod(base_rod, rod_data, pay_money)
+    }
+
+    /// Sells a prestige-exclusive rod, which isn't part of the daily stock
+    pub fn sell_prestige_rod(id: &str, rod_data: &RodData) -> Result<String, BuyError> {
+        let Some(base_rod) = rod_data.get_base(id).cloned() else {
+            return Err(BuyError::InvalidRod);
+        };
 
-        let cost = base_rod.cost;
+        let user_file = crate::data::userfile::read_userfile();
+        if base_rod.prestige == 0 || base_rod.prestige > user_file.prestige {
+            return Err(BuyError::InvalidRod);
+        }
 
-        let cost = cost.round() as u32;
+        Self::buy_base_rod(base_rod, rod_data, pay_money)
+    }
 
-        // ensure the user has enough money to buy the rod
-        if user_file.money < cost {
-            return Err(BuyError::NoMoney);
+    /// Sells a rod from a running event's stock for that event's currency
+    pub fn sell_event_rod(id: &str, event: &FishingEvent, rod_data: &RodData) -> Result<String, BuyError> {
+        let (Some(price), Some(base_rod)) = (event.get_price(id), rod_data.get_base(id).cloned()) else {
+            return Err(BuyError::InvalidRod);
+        };
+        if !event.is_active() {
+            return Err(BuyError::InvalidRod);
         }
 
-        // remove the rod from the shop
-        //let users_rod = self.rods.remove(spot);
+        Self::buy_base_rod(base_rod, rod_data, |user_file, _| {
+            let balance = user_file.event_currency.entry(event.currency.clone()).or_insert(0);
+            if *balance < price {
+                return Err(BuyError::NoMoney);
+            }
+            *balance -= price;
+            Ok(())
+        })
+    }
+
+    /// Gives the player a new rod, `pay` takes its price from the userfile
+    fn buy_base_rod(base_rod: BaseRod, rod_data: &RodData,
+                    pay: impl FnOnce(&mut UserValues, &BaseRod) -> Result<(), BuyError>) -> Result<String, BuyError> {
+        let mut user_file = crate::data::userfile::read_userfile();
+        let boat_data = crate::data::boat_data();
+        let bonuses = user_file.get_bonuses(&crate::data::skill_data());
 
-        // remove the money from the user
-        user_file.money -= cost;
+        pay(&mut user_file, &base_rod)?;
 
-        let modifier = rod_data.generate_modifier();
+        let modifiers = rod_data.roll_modifiers(BASE_MODIFIER_CHANCE, bonuses.shop_luck);
 
         // generate the rod
         let rod = Rod {
             base: base_rod,
-            modifier: modifier.clone(),
+            modifiers: modifiers.clone(),
+            bonuses,
+            tackle: user_file.get_tackle(&crate::data::tackle_data()),
+            boat: user_file.get_boat(&boat_data),
+            location: user_file.get_location(&boat_data),
         };
 
         // add the rod to the user's inventory
-        user_file.rod_name = rod.base.name.clone();
-        if let Some(m) = &rod.modifier {
-            user_file.rod_modifier = Some(m.name.clone());
-        } else {
-            user_file.rod_modifier = None;
+        user_file.rod_id = rod.base.id.clone();
+        user_file.crafted_rod = None;
+        user_file.rod_modifiers = modifiers.iter().map(|m| m.id.clone()).collect();
+
+        user_file.rods_bought += 1;
+        let unlocked = crate::data::achievement_data().check(
+            &AchievementEvent::Purchase(rod.base.id.clone()), &mut user_file, &crate::data::fish_data(), Local::now().timestamp());
+        for achievement in unlocked {
+            yay!("Achievement unlocked: {}! (+{})", achievement.name, achievement.reward);
         }
 
         // update the user's file
         crate::data::userfile::update_userfile(user_file);
 
-        if let Some(m) = &modifier {
-            Ok(format!("You now own a {}!\n  Your rod has a modifier: {}!", rod, m.name))
+        if !modifiers.is_empty() {
+            Ok(format!("You now own a {}!\n  Your rod has {} modifier(s): {}!", rod, modifiers.len(), describe_modifiers(&modifiers)))
         } else {
             Ok(format!("You now own a {}!", rod))
         }
     }
 
+    pub fn sell_component(&self, spot: usize, component_data: &ComponentData) -> Result<String, BuyError> {
+        let Some(component) = self.components.get(spot).and_then(|c| component_data.get_component_by_name(c)) else {
+            return Err(BuyError::InvalidItem);
+        };
+
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        let cost = discounted(component.cost, shop_discount(&user_file));
+
+        user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+        *user_file.components.entry(component.name.clone()).or_insert(0) += 1;
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("You bought a {}!", component.name))
+    }
+
+    /// Buys tackle and equips it, anything already in its slot goes into the tackle box
+    pub fn sell_tackle(name: &str, tackle_data: &TackleData) -> Result<String, BuyError> {
+        let Some(tackle) = tackle_data.get_tackle_by_name(name) else {
+            return Err(BuyError::InvalidItem);
+        };
+
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        if user_file.tackle.iter().chain(&user_file.tackle_box).any(|t| t.name == tackle.name) {
+            return Err(BuyError::AlreadyOwned);
+        }
+
+        let cost = discounted(tackle.cost, shop_discount(&user_file));
+
+        user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+        user_file.stow_tackle(tackle.slot, tackle_data);
+        user_file.tackle.push(OwnedTackle {
+            name: tackle.name.clone(),
+            level: 1,
+        });
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("You equipped a {}!", tackle.name))
+    }
+
+    /// Swaps tackle from the tackle box with whatever is equipped in its slot
+    pub fn equip_tackle(name: &str, tackle_data: &TackleData) -> Result<String, BuyError> {
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        if !user_file.equip_tackle(name, tackle_data) {
+            return Err(BuyError::InvalidItem);
+        }
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("You equipped your {}!", name))
+    }
+
+    pub fn upgrade_tackle(name: &str, tackle_data: &TackleData) -> Result<String, BuyError> {
+        let Some(tackle) = tackle_data.get_tackle_by_name(name) else {
+            return Err(BuyError::InvalidItem);
+        };
+
+        let mut user_file = crate::data::userfile::read_userfile();
+        let discount = shop_discount(&user_file);
+
+        let Some(owned) = user_file.tackle.iter_mut().find(|t| t.name == tackle.name) else {
+            return Err(BuyError::InvalidItem);
+        };
+
+        if owned.level >= tackle.max_level {
+            return Err(BuyError::MaxLevel);
+        }
+
+        let cost = discounted(tackle.upgrade_cost(owned.level), discount);
+
+        user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+        owned.level += 1;
+        let level = owned.level;
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("Your {} is now level {}!", tackle.name, level))
+    }
+
+    pub fn buy_auto_fisher() -> Result<String, BuyError> {
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        if user_file.auto_fisher {
+            return Err(BuyError::AlreadyOwned);
+        }
+
+        let cost = discounted(AUTO_FISHER_COST, shop_discount(&user_file));
+
+        user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+        user_file.auto_fisher = true;
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok("You now own an Auto-Fisher!".to_string())
+    }
+
+    pub fn buy_boat(id: &str, boat_data: &BoatData) -> Result<String, BuyError> {
+        let Some(boat) = boat_data.get_boat(id) else {
+            return Err(BuyError::InvalidItem);
+        };
+
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        // boats are only ever upgraded
+        let owned_tier = user_file.boat.as_ref().and_then(|b| boat_data.get_boat(b)).map(|b| b.tier).unwrap_or(0);
+        if boat.tier <= owned_tier {
+            return Err(BuyError::AlreadyOwned);
+        }
+
+        let cost = discounted(boat.cost, shop_discount(&user_file));
+
+        user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+        user_file.boat = Some(boat.id.clone());
+        // the first day of upkeep is included
+        user_file.upkeep_due = (Local::now() + Duration::days(1)).timestamp();
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("You now own a {}! Upkeep is {} a day.", boat, boat.upkeep))
+    }
+
+    /// Sells the player's boat back to the shop, ending its upkeep
+    pub fn sell_boat(boat_data: &BoatData) -> Result<String, BuyError> {
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        let boat = user_file.boat.as_ref()
+            .and_then(|b| boat_data.get_boat(b))
+            .ok_or(BuyError::InvalidItem)?;
+        let refund = boat.resale_value();
+
+        user_file.money += refund;
+        user_file.boat = None;
+        user_file.upkeep_due = 0;
+        user_file.boat_docked = false;
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("Sold your {} for {}.", boat, refund))
+    }
+
+    pub fn reforge_rod(rod_data: &RodData) -> Result<String, BuyError> {
+        let mut user_file = crate::data::userfile::read_userfile();
+
+        let mut rod = user_file.get_rod(rod_data, &crate::data::component_data(), &crate::data::tackle_data(),
+                                        &crate::data::boat_data(), &crate::data::skill_data());
+
+        let cost = rod.base.rarity.reforge_cost().ok_or(BuyError::NotReforgeable)?;
+        let cost = discounted(cost, rod.bonuses.shop_discount);
+
+        // ensure the user has enough money to reforge the rod
+        user_file.money = user_file.money.checked_sub(cost).ok_or(BuyError::NoMoney)?;
+
+        // reforging rerolls every modifier and always gives at least one, they may be the same ones
+        let modifiers = rod_data.roll_modifiers(1.0, rod.bonuses.shop_luck);
+        user_file.rod_modifiers = modifiers.iter().map(|m| m.id.clone()).collect();
+        rod.modifiers = modifiers;
+
+        crate::data::userfile::update_userfile(user_file);
+
+        Ok(format!("Your rod is now a {}! ({})", rod, describe_modifiers(&rod.modifiers)))
+    }
+
 }
\ No newline at end of file
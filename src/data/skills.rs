@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use crate::data::fish::Fish;
+use crate::data::stat_pipeline::{Stat, StatEffect};
+
+/// What a single rank of a skill does
+#[derive(Debug, Clone)]
+pub enum SkillEffect {
+    /// fraction of catch time removed per rank
+    FasterReel(f32),
+    /// catch chance added per rank
+    CatchChance(f32),
+    /// fraction of weight limit added per rank
+    WeightTolerance(f32),
+    /// fraction of the turtle chance removed per rank
+    TurtleRepellent(f32),
+    /// chance added per rank of a bought or reforged rod's modifiers never being drawbacks
+    ShopLuck(f32),
+}
+
+#[derive(Debug, Clone)]
+pub struct Skill {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub max_rank: u32,
+    /// id of a skill which needs at least one rank before this can be learned
+    pub requires: Option<String>,
+    pub effect: SkillEffect,
+}
+
+/// The combined effect of the angler's skills and prestige, carried by their `Rod`
+#[derive(Debug, Clone)]
+pub struct AnglerBonuses {
+    /// effects on rod stats, fed into the rod's `StatPipeline`
+    pub stat_effects: Vec<StatEffect>,
+    pub turtle_chance_multiplier: f32,
+    /// chance of each modifier rolled in the shop never being a drawback
+    pub shop_luck: f32,
+    pub value_multiplier: f32,
+    /// fraction taken off shop prices
+    pub shop_discount: f32,
+    pub prestige: u32,
+}
+
+impl Default for AnglerBonuses {
+    fn default() -> Self {
+        Self {
+            stat_effects: Vec::new(),
+            turtle_chance_multiplier: 1.0,
+            shop_luck: 0.0,
+            value_multiplier: 1.0,
+            shop_discount: 0.0,
+            prestige: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SkillData {
+    pub skills: Vec<Skill>,
+}
+
+impl SkillData {
+    pub fn get_skill_by_id(&self, id: &str) -> Option<&Skill> {
+        self.skills.iter().find(|skill| skill.id == id)
+    }
+
+    pub fn bonuses(&self, ranks: &HashMap<String, u32>) -> AnglerBonuses {
+        let mut bonuses = AnglerBonuses::default();
+
+        for skill in &self.skills {
+            let rank = ranks.get(&skill.id).copied().unwrap_or(0).min(skill.max_rank);
+            if rank == 0 {
+                continue;
+            }
+            let source = format!("{} (rank {})", skill.name, rank);
+            let rank = rank as f32;
+            match skill.effect {
+                SkillEffect::FasterReel(amount) => bonuses.stat_effects.push(
+                    StatEffect::multiply(source, Stat::CatchRate, (1.0 - amount * rank).max(0.1))),
+                SkillEffect::CatchChance(amount) => bonuses.stat_effects.push(
+                    StatEffect::add(source, Stat::CatchChance, amount * rank)),
+                SkillEffect::WeightTolerance(amount) => bonuses.stat_effects.push(
+                    StatEffect::multiply(source, Stat::WeightLimit, 1.0 + amount * rank)),
+                SkillEffect::TurtleRepellent(amount) => bonuses.turtle_chance_multiplier -= amount * rank,
+                SkillEffect::ShopLuck(amount) => bonuses.shop_luck += amount * rank,
+            }
+        }
+
+        bonuses.turtle_chance_multiplier = bonuses.turtle_chance_multiplier.max(0.0);
+        bonuses.shop_luck = bonuses.shop_luck.min(1.0);
+
+        bonuses
+    }
+}
+
+/// Experience earned for catching a fish, scaled by its rarity and weight
+pub fn xp_for_catch(fish: &Fish) -> u32 {
+    ((10.0 + fish.weight.sqrt() * 2.0) * fish.rarity.value_multiplier()).round() as u32
+}
+
+/// Experience needed to go from `level` to the next level
+pub fn xp_to_next_level(level: u32) -> u32 {
+    100 * level
+}
+
+/// Returns (level, xp into the current level) for a total amount of xp
+pub fn level_for_xp(xp: u32) -> (u32, u32) {
+    let mut level = 1;
+    let mut remaining = xp;
+
+    while remaining >= xp_to_next_level(level) {
+        remaining -= xp_to_next_level(level);
+        level += 1;
+    }
+
+    (level, remaining)
+}
+
+/// Skill points earned: one per level after the first
+pub fn skill_points(xp: u32) -> u32 {
+    level_for_xp(xp).0 - 1
+}
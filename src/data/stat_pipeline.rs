@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+/// A rod stat computed by the pipeline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stat {
+    /// average seconds per catch
+    CatchRate,
+    /// chance of catching a hooked fish, 0.0 to 1.0
+    CatchChance,
+    /// depth in ft
+    Depth,
+    /// weight limit in lbs
+    WeightLimit,
+}
+
+impl Stat {
+    pub fn all() -> [Self; 4] {
+        [Self::CatchRate, Self::CatchChance, Self::Depth, Self::WeightLimit]
+    }
+
+    /// The range a final value is clamped to
+    pub fn range(&self) -> (f32, f32) {
+        match self {
+            Stat::CatchRate => (2.0, f32::MAX),
+            Stat::CatchChance => (0.0, 1.0),
+            Stat::Depth => (0.0, f32::MAX),
+            Stat::WeightLimit => (0.0, f32::MAX),
+        }
+    }
+
+    fn format_value(&self, value: f32) -> String {
+        match self {
+            Stat::CatchRate => format!("{:.1}s", value),
+            Stat::CatchChance => format!("{:.1}%", value * 100.0),
+            Stat::Depth => format!("{}ft", value.round()),
+            Stat::WeightLimit => format!("{}lbs", value.round()),
+        }
+    }
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stat::CatchRate => write!(f, "Catch Rate"),
+            Stat::CatchChance => write!(f, "Catch Chance"),
+            Stat::Depth => write!(f, "Depth"),
+            Stat::WeightLimit => write!(f, "Weight Limit"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum StatOp {
+    Add(f32),
+    Multiply(f32),
+}
+
+/// One contribution to a stat, e.g. +5ft depth from a "Better" modifier
+#[derive(Debug, Clone)]
+pub struct StatEffect {
+    /// where the effect came from, shown in the breakdown
+    pub source: String,
+    pub stat: Stat,
+    pub op: StatOp,
+}
+
+impl StatEffect {
+    pub fn add<S: Into<String>>(source: S, stat: Stat, amount: f32) -> Self {
+        Self { source: source.into(), stat, op: StatOp::Add(amount) }
+    }
+
+    pub fn multiply<S: Into<String>>(source: S, stat: Stat, factor: f32) -> Self {
+        Self { source: source.into(), stat, op: StatOp::Multiply(factor) }
+    }
+}
+
+/// Collects effects from every source (rod, modifiers, tackle, skills, prestige, ...)
+/// and combines them in a fixed order:
+///
+/// 1. every additive effect is summed, in the order it was added
+/// 2. the sum is multiplied by every multiplicative effect
+/// 3. the result is clamped to the stat's range, so a negative modifier can never underflow
+#[derive(Debug, Clone, Default)]
+pub struct StatPipeline {
+    pub effects: Vec<StatEffect>,
+}
+
+impl StatPipeline {
+    pub fn push(&mut self, effect: StatEffect) {
+        self.effects.push(effect);
+    }
+
+    pub fn extend(&mut self, effects: impl IntoIterator<Item = StatEffect>) {
+        self.effects.extend(effects);
+    }
+
+    fn effects_for(&self, stat: Stat) -> impl Iterator<Item = &StatEffect> {
+        self.effects.iter().filter(move |e| e.stat == stat)
+    }
+
+    pub fn compute(&self, stat: Stat) -> f32 {
+        let sum: f32 = self.effects_for(stat)
+            .filter_map(|e| match e.op { StatOp::Add(amount) => Some(amount), StatOp::Multiply(_) => None })
+            .sum();
+        let factor: f32 = self.effects_for(stat)
+            .filter_map(|e| match e.op { StatOp::Multiply(factor) => Some(factor), StatOp::Add(_) => None })
+            .product();
+
+        let (min, max) = stat.range();
+        (sum * factor).clamp(min, max)
+    }
+
+    /// Explains where the final value of a stat came from, one line per effect
+    pub fn breakdown(&self, stat: Stat) -> String {
+        let mut lines = vec![format!("{}:", stat)];
+
+        for effect in self.effects_for(stat).filter(|e| matches!(e.op, StatOp::Add(_))) {
+            if let StatOp::Add(amount) = effect.op {
+                let sign = if amount < 0.0 { "-" } else { "+" };
+                lines.push(format!("  {}{} {}", sign, stat.format_value(amount.abs()), effect.source));
+            }
+        }
+        for effect in self.effects_for(stat) {
+            if let StatOp::Multiply(factor) = effect.op {
+                lines.push(format!("  x{:.2} {}", factor, effect.source));
+            }
+        }
+
+        let (min, max) = stat.range();
+        let unclamped: f32 = self.effects_for(stat)
+            .map(|e| match e.op { StatOp::Add(amount) => amount, StatOp::Multiply(_) => 0.0 })
+            .sum::<f32>() * self.effects_for(stat)
+            .map(|e| match e.op { StatOp::Multiply(factor) => factor, StatOp::Add(_) => 1.0 })
+            .product::<f32>();
+        if unclamped < min || unclamped > max {
+            lines.push(format!("  clamped to {}", stat.format_value(self.compute(stat))));
+        }
+
+        lines.push(format!("  = {}", stat.format_value(self.compute(stat))));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::rods::Rod;
+    use crate::data::skills::AnglerBonuses;
+
+    fn pipeline(effects: Vec<StatEffect>) -> StatPipeline {
+        StatPipeline { effects }
+    }
+
+    #[test]
+    fn adds_before_multiplying() {
+        let pipeline = pipeline(vec![
+            StatEffect::multiply("double", Stat::Depth, 2.0),
+            StatEffect::add("base", Stat::Depth, 10.0),
+            StatEffect::add("sinker", Stat::Depth, 5.0),
+            StatEffect::multiply("half", Stat::Depth, 0.5),
+            StatEffect::add("other stat", Stat::WeightLimit, 100.0),
+        ]);
+
+        // (10 + 5) * 2 * 0.5, whatever order the effects were pushed in
+        assert_eq!(pipeline.compute(Stat::Depth), 15.0);
+        assert_eq!(pipeline.compute(Stat::WeightLimit), 100.0);
+    }
+
+    #[test]
+    fn clamps_after_multiplying() {
+        // -10 * -1 would be 10 if the sum were clamped to 0 first
+        let pipeline = pipeline(vec![
+            StatEffect::add("base", Stat::Depth, -10.0),
+            StatEffect::multiply("flip", Stat::Depth, -1.0),
+        ]);
+        assert_eq!(pipeline.compute(Stat::Depth), 10.0);
+    }
+
+    #[test]
+    fn catch_rate_is_at_least_two_seconds() {
+        let pipeline = pipeline(vec![
+            StatEffect::add("base", Stat::CatchRate, 4.0),
+            StatEffect::add("reel", Stat::CatchRate, -3.0),
+            StatEffect::multiply("skill", Stat::CatchRate, 0.5),
+        ]);
+        assert_eq!(pipeline.compute(Stat::CatchRate), 2.0);
+        assert!(pipeline.breakdown(Stat::CatchRate).contains("clamped to 2.0s"));
+    }
+
+    #[test]
+    fn catch_chance_stays_between_zero_and_one() {
+        let high = pipeline(vec![
+            StatEffect::add("base", Stat::CatchChance, 0.9),
+            StatEffect::add("hook", Stat::CatchChance, 0.3),
+        ]);
+        let low = pipeline(vec![StatEffect::add("base", Stat::CatchChance, -0.2)]);
+
+        assert_eq!(high.compute(Stat::CatchChance), 1.0);
+        assert_eq!(low.compute(Stat::CatchChance), 0.0);
+    }
+
+    #[test]
+    fn negative_modifiers_never_underflow() {
+        let rod_data = crate::data::rod_data();
+        let mut base = rod_data.get_base("stick_with_string").unwrap().clone();
+        base.depth = 1;
+        base.weight_limit = 1;
+        let rod = Rod {
+            base,
+            modifiers: ["old", "worn", "light"].iter().map(|id| rod_data.get_modifier(id).unwrap().clone()).collect(),
+            bonuses: AnglerBonuses::default(),
+            tackle: Vec::new(),
+            boat: None,
+            location: crate::data::boat_data().default_location().clone(),
+        };
+
+        assert_eq!(rod.get_depth(), 0);
+        assert_eq!(rod.get_weight_limit(), 0);
+    }
+}
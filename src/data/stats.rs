@@ -0,0 +1,99 @@
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use crate::data::fish::FishRarity;
+use crate::data::history::{CatchOutcome, CatchRecord};
+use crate::data::money::Money;
+
+const INCOME_DAYS: i64 = 14;
+
+/// Aggregated numbers for the statistics panel, computed from the catch history
+#[derive(Debug, Clone, Default)]
+pub struct Stats {
+    pub total_casts: u32,
+    pub total_income: Money,
+    /// (day, income) for the last INCOME_DAYS days, oldest first
+    pub income_per_day: Vec<(NaiveDate, Money)>,
+    /// (rarity, observed share of catches, expected share from `FishRarity::get_weight`)
+    pub rarity_distribution: Vec<(FishRarity, f32, f32)>,
+    /// (base rod id, fish caught, total casts)
+    pub success_per_rod: Vec<(String, u32, u32)>,
+    /// (species id, line breaks), most breaks first
+    pub line_breaks_per_species: Vec<(String, u32)>,
+    pub average_cast_duration: f32,
+}
+
+impl Stats {
+    pub fn compute(history: &[CatchRecord]) -> Self {
+        let caught: Vec<&CatchRecord> = history.iter().filter(|r| r.outcome == CatchOutcome::Caught).collect();
+
+        // income over time
+        let today = Local::now().date_naive();
+        let income_per_day = (0..INCOME_DAYS).rev()
+            .map(|days_ago| {
+                let day = today - Duration::days(days_ago);
+                let income = caught.iter()
+                    .filter(|r| record_date(r) == Some(day))
+                    .map(|r| r.value)
+                    .sum();
+                (day, income)
+            })
+            .collect();
+
+        // catches per rarity vs the theoretical distribution
+        let total_weight: u32 = FishRarity::all().iter().map(|r| r.get_weight() as u32).sum();
+        let rarity_distribution = FishRarity::all().into_iter()
+            .map(|rarity| {
+                let count = caught.iter().filter(|r| r.rarity.ident() == rarity.ident()).count();
+                let observed = if caught.is_empty() { 0.0 } else { count as f32 / caught.len() as f32 };
+                let expected = rarity.get_weight() as f32 / total_weight as f32;
+                (rarity, observed, expected)
+            })
+            .collect();
+
+        // success rate per rod
+        let mut success_per_rod: Vec<(String, u32, u32)> = Vec::new();
+        for record in history {
+            let index = match success_per_rod.iter().position(|(rod, _, _)| rod == &record.rod) {
+                Some(i) => i,
+                None => {
+                    success_per_rod.push((record.rod.clone(), 0, 0));
+                    success_per_rod.len() - 1
+                }
+            };
+            if record.outcome == CatchOutcome::Caught {
+                success_per_rod[index].1 += 1;
+            }
+            success_per_rod[index].2 += 1;
+        }
+
+        // line breaks per species
+        let mut line_breaks_per_species: Vec<(String, u32)> = Vec::new();
+        for record in history.iter().filter(|r| r.outcome == CatchOutcome::LineBroke) {
+            match line_breaks_per_species.iter_mut().find(|(species, _)| species == &record.species) {
+                Some((_, count)) => *count += 1,
+                None => line_breaks_per_species.push((record.species.clone(), 1)),
+            }
+        }
+        line_breaks_per_species.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+        let average_cast_duration = if history.is_empty() {
+            0.0
+        } else {
+            history.iter().map(|r| r.cast_duration as f32).sum::<f32>() / history.len() as f32
+        };
+
+        Self {
+            total_casts: history.len() as u32,
+            total_income: caught.iter().map(|r| r.value).sum(),
+            income_per_day,
+            rarity_distribution,
+            success_per_rod,
+            line_breaks_per_species,
+            average_cast_duration,
+        }
+    }
+}
+
+fn record_date(record: &CatchRecord) -> Option<NaiveDate> {
+    NaiveDateTime::from_timestamp_opt(record.timestamp, 0)
+        .map(|t| t.and_utc().with_timezone(&Local).date_naive())
+}
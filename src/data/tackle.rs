@@ -0,0 +1,104 @@
+use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+use crate::data::money::Money;
+
+/// Tackle slots, each one improves a single rod stat
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TackleSlot {
+    /// weight limit in lbs
+    Line,
+    /// seconds taken off the catch rate
+    Reel,
+    /// catch chance
+    Hook,
+    /// depth in ft
+    Sinker,
+}
+
+impl TackleSlot {
+    pub fn all() -> [Self; 4] {
+        [Self::Line, Self::Reel, Self::Hook, Self::Sinker]
+    }
+}
+
+impl Display for TackleSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TackleSlot::Line => write!(f, "Line"),
+            TackleSlot::Reel => write!(f, "Reel"),
+            TackleSlot::Hook => write!(f, "Hook"),
+            TackleSlot::Sinker => write!(f, "Sinker"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Tackle {
+    pub name: String,
+    pub slot: TackleSlot,
+    /// the bonus at level 1
+    pub bonus: f32,
+    /// bonus added per upgrade
+    pub upgrade_bonus: f32,
+    pub max_level: u32,
+    pub cost: Money,
+}
+
+impl Tackle {
+    pub fn bonus_at(&self, level: u32) -> f32 {
+        self.bonus + self.upgrade_bonus * level.saturating_sub(1) as f32
+    }
+
+    /// the price of upgrading from `level` to the next level
+    pub fn upgrade_cost(&self, level: u32) -> Money {
+        Money::new(self.cost.dollars() / 2).times(u64::from(level))
+    }
+
+    /// Describes the bonus at a level, e.g. "+20lbs weight limit"
+    pub fn describe(&self, level: u32) -> String {
+        let bonus = self.bonus_at(level);
+        match self.slot {
+            TackleSlot::Line => format!("+{}lbs weight limit", bonus),
+            TackleSlot::Reel => format!("-{}s catch rate", bonus),
+            TackleSlot::Hook => format!("+{}% catch chance", (bonus * 100.0).round()),
+            TackleSlot::Sinker => format!("+{}ft depth", bonus),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TackleData {
+    pub tackle: Vec<Tackle>,
+}
+
+impl TackleData {
+    pub fn get_tackle_by_name(&self, name: &str) -> Option<&Tackle> {
+        self.tackle.iter().find(|tackle| tackle.name == name)
+    }
+}
+
+/// Tackle as stored in the userfile
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OwnedTackle {
+    pub name: String,
+    pub level: u32,
+}
+
+/// Tackle equipped on a rod
+#[derive(Debug, Clone)]
+pub struct EquippedTackle {
+    pub tackle: Tackle,
+    pub level: u32,
+}
+
+impl EquippedTackle {
+    pub fn bonus(&self) -> f32 {
+        self.tackle.bonus_at(self.level)
+    }
+}
+
+impl Display for EquippedTackle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (Lv. {})", self.tackle.name, self.level)
+    }
+}
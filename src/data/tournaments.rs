@@ -0,0 +1,472 @@
+use std::fmt::Display;
+use chrono::{Duration, Local};
+use serde::{Deserialize, Serialize};
+use crate::catch_handler::Cast;
+use crate::data::boats::{BoatData, TravelError};
+use crate::data::fish::{Fish, FishData};
+use crate::data::money::Money;
+use crate::data::rods::{Rod, RodData};
+use crate::data::skills::AnglerBonuses;
+use crate::data::userfile::UserValues;
+
+/// only the most recent results are kept in the save
+const MAX_TOURNAMENT_HISTORY: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum TournamentKind {
+    /// the heaviest single fish wins
+    HeaviestFish,
+    /// the most money earned from catches wins
+    MostValue,
+    /// the most different species wins
+    MostSpecies,
+}
+
+impl TournamentKind {
+    /// The entrant's standing, higher is better
+    pub fn score(&self, entrant: &Entrant) -> f32 {
+        match self {
+            TournamentKind::HeaviestFish => entrant.heaviest,
+            TournamentKind::MostValue => entrant.value.as_f32(),
+            TournamentKind::MostSpecies => entrant.species.len() as f32,
+        }
+    }
+
+    pub fn describe_score(&self, entrant: &Entrant) -> String {
+        match self {
+            TournamentKind::HeaviestFish => format!("{}lbs", entrant.heaviest),
+            TournamentKind::MostValue => entrant.value.to_string(),
+            TournamentKind::MostSpecies => format!("{} species", entrant.species.len()),
+        }
+    }
+}
+
+impl Display for TournamentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TournamentKind::HeaviestFish => write!(f, "Heaviest Fish"),
+            TournamentKind::MostValue => write!(f, "Most Value"),
+            TournamentKind::MostSpecies => write!(f, "Most Species"),
+        }
+    }
+}
+
+/// A computer controlled competitor
+#[derive(Debug, Clone)]
+pub struct AiAngler {
+    pub name: String,
+    /// id of the rod they fish with
+    pub rod: String,
+    /// scales the rod's catch chance, like the auto-fisher
+    pub efficiency: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Tournament {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub kind: TournamentKind,
+    /// location id, only catches made here count
+    pub location: String,
+    pub minutes: i64,
+    pub entry_fee: Money,
+    /// prize for each placement, first place first
+    pub prizes: Vec<Money>,
+    pub anglers: Vec<AiAngler>,
+}
+
+impl Tournament {
+    fn get_angler(&self, name: &str) -> Option<&AiAngler> {
+        self.anglers.iter().find(|angler| angler.name == name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TournamentData {
+    pub tournaments: Vec<Tournament>,
+}
+
+impl TournamentData {
+    pub fn get_tournament(&self, id: &str) -> Option<&Tournament> {
+        self.tournaments.iter().find(|t| t.id == id)
+    }
+}
+
+/// Someone fishing in a running tournament
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Entrant {
+    pub name: String,
+    pub is_player: bool,
+    /// timestamp an ai angler's current cast finishes
+    pub next_cast: i64,
+    pub heaviest: f32,
+    pub value: Money,
+    /// ids of the species caught
+    pub species: Vec<String>,
+}
+
+impl Entrant {
+    fn new(name: &str, is_player: bool, now: i64) -> Self {
+        Self {
+            name: name.to_string(),
+            is_player,
+            next_cast: now,
+            heaviest: 0.0,
+            value: Money::ZERO,
+            species: Vec::new(),
+        }
+    }
+
+    fn record_catch(&mut self, fish: &Fish, value: Money) {
+        self.heaviest = self.heaviest.max(fish.weight);
+        self.value += value;
+        if !self.species.contains(&fish.fish_type.id) {
+            self.species.push(fish.fish_type.id.clone());
+        }
+    }
+}
+
+/// The tournament the player has entered
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ActiveTournament {
+    pub id: String,
+    pub ends_at: i64,
+    pub entrants: Vec<Entrant>,
+}
+
+impl ActiveTournament {
+    /// Entrants from first place to last
+    pub fn standings(&self, kind: TournamentKind) -> Vec<&Entrant> {
+        let mut standings: Vec<&Entrant> = self.entrants.iter().collect();
+        standings.sort_by(|a, b| kind.score(b).total_cmp(&kind.score(a)));
+        standings
+    }
+
+    pub fn get_time_remaining(&self) -> String {
+        let remaining = (self.ends_at - Local::now().timestamp()).max(0);
+        format!("{}:{:02}", remaining / 60, remaining % 60)
+    }
+
+    /// Fishes for the ai anglers up to `now`, or the end of the tournament if that's sooner
+    fn simulate_anglers(&mut self, tournament: &Tournament, now: i64, rod_data: &RodData, fish_data: &FishData, boat_data: &BoatData) {
+        let until = now.min(self.ends_at);
+        let Some(location) = boat_data.get_location(&tournament.location) else {
+            return;
+        };
+
+        for entrant in self.entrants.iter_mut().filter(|e| !e.is_player) {
+            let Some(angler) = tournament.get_angler(&entrant.name) else {
+                continue;
+            };
+            let Some(base) = rod_data.get_base(&angler.rod) else {
+                continue;
+            };
+            let rod = Rod {
+                base: base.clone(),
+                modifiers: Vec::new(),
+                bonuses: AnglerBonuses::default(),
+                tackle: Vec::new(),
+                boat: None,
+                location: location.clone(),
+            };
+
+            // the same casts the player makes, resolved as soon as they'd have finished
+            while entrant.next_cast <= until {
+                let Ok(cast) = Cast::plan(&rod, fish_data, angler.efficiency) else {
+                    break;
+                };
+                entrant.next_cast += cast.duration.num_seconds().max(1);
+                if entrant.next_cast > self.ends_at {
+                    break;
+                }
+
+                if cast.will_catch && cast.fish.weight as u32 <= rod.get_weight_limit() {
+                    entrant.record_catch(&cast.fish, cast.fish.get_value(fish_data));
+                }
+            }
+        }
+    }
+}
+
+/// How the player did in a finished tournament
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TournamentResult {
+    pub name: String,
+    pub kind: TournamentKind,
+    /// 1 for first place
+    pub placement: usize,
+    pub entrants: usize,
+    pub score: String,
+    pub prize: Money,
+    pub finished_at: i64,
+}
+
+impl Display for TournamentResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: placed {} of {} with {}", self.name, ordinal(self.placement), self.entrants, self.score)
+    }
+}
+
+/// 1 -> "1st", 2 -> "2nd", 11 -> "11th"
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+#[derive(Debug, Clone)]
+pub enum TournamentError {
+    AlreadyEntered,
+    NoMoney,
+    CantReach(TravelError),
+}
+
+impl Display for TournamentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TournamentError::AlreadyEntered => write!(f, "You're already in a tournament!"),
+            TournamentError::NoMoney => write!(f, "You can't afford the entry fee!"),
+            TournamentError::CantReach(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// Pays the entry fee and starts the tournament, if the player can get to where it's held
+pub fn enter_tournament(userfile: &mut UserValues, tournament: &Tournament, boat_data: &BoatData) -> Result<(), TournamentError> {
+    if userfile.tournament.is_some() {
+        return Err(TournamentError::AlreadyEntered);
+    }
+    userfile.can_travel_to(&tournament.location, boat_data).map_err(TournamentError::CantReach)?;
+    userfile.money = userfile.money.checked_sub(tournament.entry_fee).ok_or(TournamentError::NoMoney)?;
+
+    let now = Local::now().timestamp();
+    let mut entrants = vec![Entrant::new("You", true, now)];
+    entrants.extend(tournament.anglers.iter().map(|angler| Entrant::new(&angler.name, false, now)));
+
+    userfile.tournament = Some(ActiveTournament {
+        id: tournament.id.clone(),
+        ends_at: now + Duration::minutes(tournament.minutes).num_seconds(),
+        entrants,
+    });
+
+    Ok(())
+}
+
+/// Counts a fish the player caught at `now`, if they're fishing in a running tournament
+pub fn record_player_catch(userfile: &mut UserValues, fish: &Fish, value: Money, location: &str,
+                           tournament_data: &TournamentData, now: i64) {
+    let Some(active) = userfile.tournament.as_mut() else {
+        return;
+    };
+    let Some(tournament) = tournament_data.get_tournament(&active.id) else {
+        return;
+    };
+    if tournament.location != location || now > active.ends_at {
+        return;
+    }
+
+    if let Some(player) = active.entrants.iter_mut().find(|e| e.is_player) {
+        player.record_catch(fish, value);
+    }
+}
+
+/// Brings the ai anglers up to `now` and pays out the tournament once it's over,
+/// returns a message when it finishes
+pub fn update_tournament(userfile: &mut UserValues, tournament_data: &TournamentData, rod_data: &RodData,
+                         fish_data: &FishData, boat_data: &BoatData, now: i64) -> Option<String> {
+    let active = userfile.tournament.as_mut()?;
+    let Some(tournament) = tournament_data.get_tournament(&active.id) else {
+        userfile.tournament = None;
+        return None;
+    };
+
+    active.simulate_anglers(tournament, now, rod_data, fish_data, boat_data);
+    if now < active.ends_at {
+        return None;
+    }
+
+    let standings = active.standings(tournament.kind);
+    let placement = standings.iter().position(|e| e.is_player).map_or(standings.len(), |i| i + 1);
+    let score = standings.iter().find(|e| e.is_player).map_or(String::new(), |e| tournament.kind.describe_score(e));
+    let prize = tournament.prizes.get(placement - 1).copied().unwrap_or(Money::ZERO);
+
+    let result = TournamentResult {
+        name: tournament.name.clone(),
+        kind: tournament.kind,
+        placement,
+        entrants: standings.len(),
+        score,
+        prize,
+        finished_at: now,
+    };
+
+    userfile.money += prize;
+    userfile.tournament = None;
+    userfile.tournament_history.push(result.clone());
+    if userfile.tournament_history.len() > MAX_TOURNAMENT_HISTORY {
+        userfile.tournament_history.remove(0);
+    }
+
+    if prize > Money::ZERO {
+        Some(format!("🏆 {}! You won {} 🏆", result, prize))
+    } else {
+        Some(format!("🏆 {} 🏆", result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::shop::RodRarity;
+
+    fn tournament(kind: TournamentKind) -> Tournament {
+        Tournament {
+            id: "test_tournament".to_string(),
+            name: "Test Tournament".to_string(),
+            description: String::new(),
+            kind,
+            location: "lakeshore".to_string(),
+            minutes: 10,
+            entry_fee: Money::new(100),
+            prizes: vec![Money::new(1000), Money::new(400)],
+            anglers: Vec::new(),
+        }
+    }
+
+    fn entrant(name: &str, is_player: bool, heaviest: f32, value: u64, species: &[&str]) -> Entrant {
+        Entrant {
+            heaviest,
+            value: Money::new(value),
+            species: species.iter().map(|s| s.to_string()).collect(),
+            ..Entrant::new(name, is_player, 0)
+        }
+    }
+
+    fn running(ends_at: i64) -> ActiveTournament {
+        ActiveTournament {
+            id: "test_tournament".to_string(),
+            ends_at,
+            entrants: vec![
+                entrant("You", true, 12.0, 50, &["a"]),
+                entrant("Bob", false, 20.0, 10, &["a", "b"]),
+                entrant("Sue", false, 5.0, 80, &["a", "b", "c"]),
+            ],
+        }
+    }
+
+    fn fish(weight: f32) -> Fish {
+        let fish_data = crate::data::fish_data();
+        Fish {
+            fish_type: fish_data.fish[0].clone(),
+            rarity: crate::data::fish::FishRarity::Common,
+            weight,
+        }
+    }
+
+    fn names(standings: Vec<&Entrant>) -> Vec<&str> {
+        standings.into_iter().map(|e| e.name.as_str()).collect()
+    }
+
+    #[test]
+    fn standings_follow_the_kind() {
+        let active = running(0);
+
+        assert_eq!(names(active.standings(TournamentKind::HeaviestFish)), ["Bob", "You", "Sue"]);
+        assert_eq!(names(active.standings(TournamentKind::MostValue)), ["Sue", "You", "Bob"]);
+        assert_eq!(names(active.standings(TournamentKind::MostSpecies)), ["Sue", "Bob", "You"]);
+    }
+
+    #[test]
+    fn ordinals() {
+        let ordinals: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 22, 23, 101, 111].into_iter().map(ordinal).collect();
+        assert_eq!(ordinals, ["1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "22nd", "23rd", "101st", "111th"]);
+    }
+
+    #[test]
+    fn prizes_are_paid_by_placement() {
+        let data = TournamentData { tournaments: vec![tournament(TournamentKind::MostValue)] };
+        let mut userfile = UserValues {
+            tournament: Some(running(Local::now().timestamp() - 1)),
+            ..Default::default()
+        };
+
+        let message = update_tournament(&mut userfile, &data, &crate::data::rod_data(),
+                                        &crate::data::fish_data(), &crate::data::boat_data(), Local::now().timestamp());
+
+        assert!(message.is_some());
+        assert!(userfile.tournament.is_none());
+        assert_eq!(userfile.money, Money::new(400));
+        let result = userfile.tournament_history.last().unwrap();
+        assert_eq!((result.placement, result.entrants, result.prize), (2, 3, Money::new(400)));
+    }
+
+    #[test]
+    fn running_tournaments_pay_nothing() {
+        let data = TournamentData { tournaments: vec![tournament(TournamentKind::MostValue)] };
+        let mut userfile = UserValues {
+            tournament: Some(running(Local::now().timestamp() + 600)),
+            ..Default::default()
+        };
+
+        let message = update_tournament(&mut userfile, &data, &crate::data::rod_data(),
+                                        &crate::data::fish_data(), &crate::data::boat_data(), Local::now().timestamp());
+
+        assert!(message.is_none());
+        assert!(userfile.tournament.is_some());
+        assert_eq!(userfile.money, Money::ZERO);
+    }
+
+    #[test]
+    fn only_catches_at_the_venue_count() {
+        let data = TournamentData { tournaments: vec![tournament(TournamentKind::HeaviestFish)] };
+        let mut userfile = UserValues {
+            tournament: Some(running(Local::now().timestamp() + 600)),
+            ..Default::default()
+        };
+        let player = |userfile: &UserValues| userfile.tournament.as_ref().unwrap().entrants[0].clone();
+
+        record_player_catch(&mut userfile, &fish(30.0), Money::new(5), "open_ocean", &data, Local::now().timestamp());
+        assert_eq!(player(&userfile).heaviest, 12.0);
+
+        record_player_catch(&mut userfile, &fish(30.0), Money::new(5), "lakeshore", &data, Local::now().timestamp());
+        assert_eq!(player(&userfile).heaviest, 30.0);
+        assert_eq!(player(&userfile).value, Money::new(55));
+
+        userfile.tournament.as_mut().unwrap().ends_at = Local::now().timestamp() - 1;
+        record_player_catch(&mut userfile, &fish(40.0), Money::new(5), "lakeshore", &data, Local::now().timestamp());
+        assert_eq!(player(&userfile).heaviest, 30.0);
+    }
+
+    #[test]
+    fn entering_needs_a_way_there() {
+        let boat_data = crate::data::boat_data();
+        let mut coastal = tournament(TournamentKind::HeaviestFish);
+        coastal.location = "coastal_waters".to_string();
+        let mut userfile = UserValues { money: Money::new(500), ..Default::default() };
+
+        assert!(matches!(enter_tournament(&mut userfile, &coastal, &boat_data), Err(TournamentError::CantReach(_))));
+        assert_eq!(userfile.money, Money::new(500));
+
+        enter_tournament(&mut userfile, &tournament(TournamentKind::HeaviestFish), &boat_data).unwrap();
+        assert_eq!(userfile.money, Money::new(400));
+        assert_eq!(userfile.tournament.as_ref().unwrap().entrants.len(), 1);
+    }
+
+    #[test]
+    fn anglers_fish_with_shop_rods() {
+        let rod_data = crate::data::rod_data();
+
+        for tournament in crate::data::tournament_data().tournaments {
+            for angler in &tournament.anglers {
+                let base = rod_data.get_base(&angler.rod).unwrap();
+                assert!(!matches!(base.rarity, RodRarity::Unobtainable), "{} in {}", angler.name, tournament.id);
+            }
+        }
+    }
+}
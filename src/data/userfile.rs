@@ -1,40 +1,423 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 use std::path::Path;
+use std::sync::Once;
 use serde::{Deserialize, Serialize};
-use crate::data::rods::{Rod, RodData};
+use chrono::Local;
+use crate::data::aquarium::Aquarium;
+use crate::data::boats::{Boat, BoatData, Location, TravelError};
+use crate::data::quests::{QuestBoard, QuestReward};
+use crate::data::money::Money;
+use crate::data::records::SpeciesRecord;
+use crate::data::rods::{MAX_MODIFIERS, Rod, RodData};
+use crate::data::components::{ComponentData, CraftedRod};
+use crate::data::prestige::apply_prestige;
+use crate::data::skills::{AnglerBonuses, skill_points, SkillData};
+use crate::data::tackle::{EquippedTackle, OwnedTackle, TackleData, TackleSlot};
+use crate::data::tournaments::{ActiveTournament, TournamentResult};
 use crate::nay;
 
+#[derive(Debug, Clone)]
+pub enum SkillError {
+    NoPoints,
+    MaxRank,
+    Locked,
+    UnknownSkill,
+}
+
+#[derive(Debug, Clone)]
+pub enum ModifierError {
+    UnknownModifier,
+    RodFull,
+    AlreadyFitted,
+}
+
+impl Display for ModifierError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModifierError::UnknownModifier => write!(f, "That modifier doesn't exist!"),
+            ModifierError::RodFull => write!(f, "Your rod is full, pick a modifier to replace!"),
+            ModifierError::AlreadyFitted => write!(f, "Your rod already has that modifier!"),
+        }
+    }
+}
+
+impl Display for SkillError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkillError::NoPoints => write!(f, "You don't have any skill points!"),
+            SkillError::MaxRank => write!(f, "That skill is already maxed out!"),
+            SkillError::Locked => write!(f, "You need to learn the previous skill first!"),
+            SkillError::UnknownSkill => write!(f, "That skill doesn't exist!"),
+        }
+    }
+}
+
+/// only the first missing rod is logged, `get_rod` is called every frame
+static MISSING_ROD: Once = Once::new();
+
+/// bumped whenever older saves need changing when they're loaded, they're migrated once and saved again
+const SAVE_VERSION: u32 = 1;
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct UserValues {
+    /// the `SAVE_VERSION` this save was last migrated to, 0 for saves from before versions
+    #[serde(default)]
+    pub version: u32,
     pub fish_caught: u32,
-    pub money: u32,
-    pub rod_name: String,
+    pub money: Money,
+    /// id of the equipped shop rod
+    #[serde(alias = "rod_name")]
+    pub rod_id: String,
+    /// only read from older saves, moved into `rod_modifiers` when loaded
+    #[serde(default, skip_serializing)]
     pub rod_modifier: Option<String>,
-    pub has_seen: Vec<String>
+    #[serde(default)]
+    pub rod_modifiers: Vec<String>,
+    /// modifiers won that the player hasn't fitted to their rod or thrown away yet
+    #[serde(default)]
+    pub pending_modifiers: Vec<String>,
+    pub has_seen: Vec<String>,
+    #[serde(default)]
+    pub line_breaks: u32,
+    #[serde(default)]
+    pub rods_bought: u32,
+    /// achievement id -> unlock timestamp
+    #[serde(default)]
+    pub achievements: HashMap<String, i64>,
+    #[serde(default)]
+    pub quests: QuestBoard,
+    /// species id -> personal records
+    #[serde(default)]
+    pub records: HashMap<String, SpeciesRecord>,
+    #[serde(default)]
+    pub auto_fisher: bool,
+    #[serde(default)]
+    pub auto_fish_enabled: bool,
+    /// timestamp of the last time the game was running, used for offline progress
+    #[serde(default)]
+    pub last_seen: i64,
+    #[serde(default)]
+    pub xp: u32,
+    /// skill id -> rank
+    #[serde(default)]
+    pub skills: HashMap<String, u32>,
+    #[serde(default)]
+    pub prestige: u32,
+    /// species seen since the last prestige, each prestige needs the bestiary filled in again
+    #[serde(default)]
+    pub seen_this_prestige: Vec<String>,
+    /// component name -> amount owned
+    #[serde(default)]
+    pub components: HashMap<String, u32>,
+    /// the equipped crafted rod, replaces `rod_id` when set
+    #[serde(default)]
+    pub crafted_rod: Option<CraftedRod>,
+    /// tackle equipped in each slot
+    #[serde(default)]
+    pub tackle: Vec<OwnedTackle>,
+    /// tackle that has been swapped out, kept with its upgrades
+    #[serde(default)]
+    pub tackle_box: Vec<OwnedTackle>,
+    /// id of the player's boat
+    #[serde(default)]
+    pub boat: Option<String>,
+    /// timestamp the next day of boat upkeep is due, the boat is docked while this is overdue
+    #[serde(default)]
+    pub upkeep_due: i64,
+    /// set once the player has been told their boat is docked, cleared when upkeep is paid
+    #[serde(default)]
+    pub boat_docked: bool,
+    /// id of the chosen location, `None` for the default
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub aquarium: Aquarium,
+    /// collection set id -> completion timestamp
+    #[serde(default)]
+    pub collections: HashMap<String, i64>,
+    /// event currency name -> amount
+    #[serde(default)]
+    pub event_currency: HashMap<String, u32>,
+    /// random event id -> times it has happened
+    #[serde(default)]
+    pub random_events: HashMap<String, u32>,
+    /// loot item id -> amount owned
+    #[serde(default)]
+    pub loot: HashMap<String, u32>,
+    /// id of every loot item ever reeled in
+    #[serde(default)]
+    pub items_seen: Vec<String>,
+    /// the tournament the player is fishing in
+    #[serde(default)]
+    pub tournament: Option<ActiveTournament>,
+    /// finished tournaments, oldest first
+    #[serde(default)]
+    pub tournament_history: Vec<TournamentResult>,
 }
 
 impl UserValues {
-    pub fn get_rod(&self, rod_data: &RodData) -> Rod {
+    pub fn get_rod(&self, rod_data: &RodData, component_data: &ComponentData, tackle_data: &TackleData,
+                   boat_data: &BoatData, skill_data: &SkillData) -> Rod {
+        // a component or rod the save refers to may have been renamed or removed since
+        let crafted_base = self.crafted_rod.as_ref().and_then(|crafted| {
+            let base = crafted.to_base(component_data);
+            if base.is_none() {
+                MISSING_ROD.call_once(|| nay!("Your crafted rod uses a component that no longer exists, using your shop rod instead"));
+            }
+            base
+        });
+        let rod_base = crafted_base.unwrap_or_else(|| match rod_data.get_base(&self.rod_id) {
+            Some(base) => base.clone(),
+            None => {
+                MISSING_ROD.call_once(|| nay!("Your rod {} no longer exists, using the starter rod instead", self.rod_id));
+                rod_data.get_base(&UserValues::default().rod_id).unwrap().clone()
+            }
+        });
 
-        let rod_base = rod_data.get_base_by_name(self.rod_name.as_str()).unwrap();
-
-        let modifier = self.rod_modifier.as_ref().map(|modifier|
-            rod_data.get_modifier_by_name(modifier.as_str()).unwrap());
+        let modifiers = self.rod_modifiers.iter()
+            .filter_map(|modifier| rod_data.get_modifier(modifier).cloned())
+            .collect();
 
         Rod {
             base: rod_base,
-            modifier,
+            modifiers,
+            bonuses: self.get_bonuses(skill_data),
+            tackle: self.get_tackle(tackle_data),
+            boat: self.get_boat(boat_data),
+            location: self.get_location(boat_data),
+        }
+    }
+
+    /// The player's boat, or `None` if they don't have one or it's docked for unpaid upkeep
+    pub fn get_boat(&self, boat_data: &BoatData) -> Option<Boat> {
+        if self.upkeep_due <= Local::now().timestamp() {
+            return None;
+        }
+
+        self.boat.as_ref().and_then(|id| boat_data.get_boat(id).cloned())
+    }
+
+    /// The chosen location, falling back to the default if the player's boat can't reach it
+    pub fn get_location(&self, boat_data: &BoatData) -> Location {
+        let tier = self.get_boat(boat_data).map(|b| b.tier).unwrap_or(0);
+
+        self.location.as_ref()
+            .and_then(|id| boat_data.get_location(id))
+            .filter(|location| location.tier <= tier)
+            .unwrap_or(boat_data.default_location())
+            .clone()
+    }
+
+    /// The location with this id, if the player's boat can get them there
+    pub fn can_travel_to<'a>(&self, id: &str, boat_data: &'a BoatData) -> Result<&'a Location, TravelError> {
+        let location = boat_data.get_location(id).ok_or(TravelError::UnknownLocation)?;
+
+        if location.tier > self.get_boat(boat_data).map(|b| b.tier).unwrap_or(0) {
+            return Err(TravelError::NeedsBoat(location.tier));
+        }
+
+        Ok(location)
+    }
+
+    pub fn travel_to(&mut self, id: &str, boat_data: &BoatData) -> Result<(), TravelError> {
+        let location = self.can_travel_to(id, boat_data)?;
+        self.location = Some(location.id.clone());
+        Ok(())
+    }
+
+    pub fn get_tackle(&self, tackle_data: &TackleData) -> Vec<EquippedTackle> {
+        self.tackle.iter()
+            .filter_map(|owned| tackle_data.get_tackle_by_name(&owned.name).map(|tackle| EquippedTackle {
+                tackle: tackle.clone(),
+                level: owned.level,
+            }))
+            .collect()
+    }
+
+    /// Moves whatever is equipped in `slot` into the tackle box
+    pub fn stow_tackle(&mut self, slot: TackleSlot, tackle_data: &TackleData) {
+        let (stowed, equipped): (Vec<OwnedTackle>, Vec<OwnedTackle>) = self.tackle.drain(..)
+            .partition(|t| tackle_data.get_tackle_by_name(&t.name).is_some_and(|tackle| tackle.slot == slot));
+        self.tackle = equipped;
+        self.tackle_box.extend(stowed);
+    }
+
+    /// Equips tackle from the tackle box in place of whatever is in its slot, false if it isn't in the box
+    pub fn equip_tackle(&mut self, name: &str, tackle_data: &TackleData) -> bool {
+        let (Some(tackle), Some(index)) = (tackle_data.get_tackle_by_name(name), self.tackle_box.iter().position(|t| t.name == name)) else {
+            return false;
+        };
+
+        self.stow_tackle(tackle.slot, tackle_data);
+        let owned = self.tackle_box.remove(index);
+        self.tackle.push(owned);
+        true
+    }
+
+    /// The combined bonuses from the player's skills and prestige
+    pub fn get_bonuses(&self, skill_data: &SkillData) -> AnglerBonuses {
+        let mut bonuses = skill_data.bonuses(&self.skills);
+        apply_prestige(&mut bonuses, self.prestige);
+        bonuses
+    }
+
+    pub fn unspent_skill_points(&self) -> u32 {
+        skill_points(self.xp).saturating_sub(self.skills.values().sum())
+    }
+
+    pub fn learn_skill(&mut self, skill_data: &SkillData, id: &str) -> Result<(), SkillError> {
+        let skill = skill_data.get_skill_by_id(id).ok_or(SkillError::UnknownSkill)?;
+
+        if let Some(required) = &skill.requires {
+            if self.skills.get(required).copied().unwrap_or(0) == 0 {
+                return Err(SkillError::Locked);
+            }
+        }
+
+        let rank = self.skills.get(id).copied().unwrap_or(0);
+        if rank >= skill.max_rank {
+            return Err(SkillError::MaxRank);
+        }
+
+        if self.unspent_skill_points() == 0 {
+            return Err(SkillError::NoPoints);
+        }
+
+        self.skills.insert(id.to_string(), rank + 1);
+        Ok(())
+    }
+
+    /// Pays out a quest reward, returning a message describing it
+    pub fn claim_reward(&mut self, reward: &QuestReward, rod_data: &RodData) -> String {
+        match reward {
+            QuestReward::Money(amount) => {
+                self.money += *amount;
+                format!("+{}", amount)
+            }
+            QuestReward::Modifier(id) => {
+                if self.rod_modifiers.contains(id) {
+                    return format!("your rod already has the {} modifier", rod_data.modifier_name(id));
+                }
+                // the player decides what a full rod gives up, and whether to take a drawback older quests promised
+                let is_drawback = rod_data.get_modifier(id).is_some_and(|m| m.is_drawback());
+                if self.rod_modifiers.len() >= MAX_MODIFIERS || is_drawback {
+                    self.pending_modifiers.push(id.clone());
+                    return format!("the {} modifier is waiting in the shop for you to fit it", rod_data.modifier_name(id));
+                }
+                self.rod_modifiers.push(id.clone());
+                format!("your rod gained the {} modifier", rod_data.modifier_name(id))
+            }
+        }
+    }
+
+    /// Fits a pending modifier to the rod, in place of the modifier at `replace` if given
+    pub fn fit_pending_modifier(&mut self, index: usize, replace: Option<usize>, rod_data: &RodData) -> Result<String, ModifierError> {
+        let id = self.pending_modifiers.get(index).ok_or(ModifierError::UnknownModifier)?.clone();
+        if self.rod_modifiers.contains(&id) {
+            return Err(ModifierError::AlreadyFitted);
+        }
+
+        let replaced = match replace {
+            Some(slot) if slot < self.rod_modifiers.len() => Some(std::mem::replace(&mut self.rod_modifiers[slot], id.clone())),
+            Some(_) => return Err(ModifierError::UnknownModifier),
+            None if self.rod_modifiers.len() >= MAX_MODIFIERS => return Err(ModifierError::RodFull),
+            None => {
+                self.rod_modifiers.push(id.clone());
+                None
+            }
+        };
+        self.pending_modifiers.remove(index);
+
+        Ok(match replaced {
+            Some(old) => format!("Your rod swapped its {} modifier for {}!", rod_data.modifier_name(&old), rod_data.modifier_name(&id)),
+            None => format!("Your rod gained the {} modifier!", rod_data.modifier_name(&id)),
+        })
+    }
+
+    /// Throws away a pending modifier
+    pub fn discard_pending_modifier(&mut self, index: usize, rod_data: &RodData) -> Result<String, ModifierError> {
+        if index >= self.pending_modifiers.len() {
+            return Err(ModifierError::UnknownModifier);
+        }
+        let id = self.pending_modifiers.remove(index);
+        Ok(format!("You threw away the {} modifier.", rod_data.modifier_name(&id)))
+    }
+
+    /// Brings a save from an older version of the game up to date
+    fn migrate(&mut self) {
+        if let Some(modifier) = self.rod_modifier.take() {
+            self.rod_modifiers.push(modifier);
         }
+        // junk used to be caught as fish
+        let loot_data = crate::data::loot_data();
+        for name in std::mem::take(&mut self.has_seen) {
+            match loot_data.items.iter().find(|item| item.name == name) {
+                Some(item) if !self.items_seen.contains(&item.id) => self.items_seen.push(item.id.clone()),
+                Some(_) => {}
+                None => self.has_seen.push(name),
+            }
+        }
+        self.migrate_ids();
+        // saves from before prestige count everything they've seen towards the first one
+        self.seen_this_prestige = self.has_seen.clone();
+
+        self.version = SAVE_VERSION;
     }
+
+    /// Swaps the display names older saves stored for the ids they refer to
+    fn migrate_ids(&mut self) {
+        let fish_data = crate::data::fish_data();
+        let rod_data = crate::data::rod_data();
+
+        rod_data.rods.migrate(&mut self.rod_id);
+        for modifier in &mut self.rod_modifiers {
+            rod_data.modifiers.migrate(modifier);
+        }
+        for species in &mut self.has_seen {
+            fish_data.fish.migrate(species);
+        }    }
 }
 
 impl Default for UserValues {
     fn default() -> Self {
         Self {
+            version: SAVE_VERSION,
             fish_caught: 0,
-            money: 0,
-            rod_name: "Stick with String".to_string(),
+            money: Money::ZERO,
+            rod_id: "stick_with_string".to_string(),
             rod_modifier: None,
-            has_seen: vec![]
+            rod_modifiers: Vec::new(),
+            pending_modifiers: Vec::new(),
+            has_seen: vec![],
+            line_breaks: 0,
+            rods_bought: 0,
+            achievements: HashMap::new(),
+            quests: QuestBoard::default(),
+            records: HashMap::new(),
+            auto_fisher: false,
+            auto_fish_enabled: false,
+            last_seen: 0,
+            xp: 0,
+            skills: HashMap::new(),
+            prestige: 0,
+            seen_this_prestige: vec![],
+            components: HashMap::new(),
+            crafted_rod: None,
+            tackle: Vec::new(),
+            tackle_box: Vec::new(),
+            boat: None,
+            upkeep_due: 0,
+            boat_docked: false,
+            location: None,
+            aquarium: Aquarium::default(),
+            collections: HashMap::new(),
+            event_currency: HashMap::new(),
+            random_events: HashMap::new(),
+            loot: HashMap::new(),
+            items_seen: Vec::new(),
+            tournament: None,
+            tournament_history: Vec::new(),
         }
     }
 }
@@ -64,7 +447,10 @@ pub fn create_userfile() {
 
     let serialized = serde_json::to_string(&user_values).unwrap();
 
-    std::fs::write(path, serialized).unwrap();
+    // written next to the save and moved over it, so it's never read half written
+    let temp_path = path.with_extension("json.tmp");
+    std::fs::write(&temp_path, serialized).unwrap();
+    std::fs::rename(temp_path, path).unwrap();
 }
 
 pub fn read_userfile() -> UserValues {
@@ -77,7 +463,25 @@ pub fn read_userfile() -> UserValues {
 
     let contents = std::fs::read_to_string(path).unwrap();
 
-    serde_json::from_str(contents.as_str()).unwrap()
+    let mut user_values: UserValues = serde_json::from_str(contents.as_str()).unwrap();
+    if user_values.version < SAVE_VERSION {
+        user_values.migrate();
+        update_userfile(user_values.clone());
+    }
+
+    user_values
+}
+
+/// Reads the save without creating or rewriting it, for threads that mustn't take the game down with them
+pub fn try_read_userfile() -> std::io::Result<UserValues> {
+    let contents = std::fs::read_to_string(get_userfile_path())?;
+
+    let mut user_values: UserValues = serde_json::from_str(contents.as_str())?;
+    if user_values.version < SAVE_VERSION {
+        user_values.migrate();
+    }
+
+    Ok(user_values)
 }
 
 pub fn update_userfile(user_values: UserValues) {
@@ -92,3 +496,122 @@ pub fn update_userfile(user_values: UserValues) {
 
     std::fs::write(path, serialized).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_rod() -> UserValues {
+        UserValues {
+            rod_modifiers: vec!["masterwork".to_string(), "upgraded".to_string(), "deep_diving".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rewards_never_roll_drawbacks() {
+        let rod_data = crate::data::rod_data();
+        for _ in 0..1000 {
+            assert!(!rod_data.roll_reward_modifier().is_drawback());
+        }
+    }
+
+    #[test]
+    fn lucky_buyers_skip_drawbacks() {
+        let rod_data = crate::data::rod_data();
+        let ranks = HashMap::from([("lucky_buyer".to_string(), 4)]);
+        let luck = crate::data::skill_data().bonuses(&ranks).shop_luck;
+        assert_eq!(luck, 1.0);
+
+        for _ in 0..1000 {
+            assert!(rod_data.roll_modifiers(1.0, luck).iter().all(|m| !m.is_drawback()));
+        }
+    }
+
+    #[test]
+    fn older_saves_are_migrated_to_ids() {
+        let mut userfile = UserValues {
+            version: 0,
+            has_seen: vec!["Trout".to_string(), "Old Boot".to_string()],
+            rod_modifier: Some("sharp".to_string()),
+            ..Default::default()
+        };
+
+        userfile.migrate();
+
+        assert_eq!(userfile.version, SAVE_VERSION);
+        assert_eq!(userfile.has_seen, ["trout"]);
+        assert_eq!(userfile.items_seen, ["old_boot"]);
+        assert_eq!(userfile.rod_modifiers, ["sharp"]);
+    }
+
+    #[test]
+    fn swapped_tackle_keeps_its_upgrades() {
+        let tackle_data = crate::data::tackle_data();
+        let lines: Vec<&str> = tackle_data.tackle.iter().filter(|t| t.slot == TackleSlot::Line).map(|t| t.name.as_str()).collect();
+        let mut userfile = UserValues {
+            tackle: vec![OwnedTackle { name: lines[0].to_string(), level: 3 }],
+            tackle_box: vec![OwnedTackle { name: lines[1].to_string(), level: 2 }],
+            ..Default::default()
+        };
+
+        assert!(userfile.equip_tackle(lines[1], &tackle_data));
+        assert_eq!(userfile.tackle.iter().map(|t| (t.name.as_str(), t.level)).collect::<Vec<_>>(), [(lines[1], 2)]);
+        assert_eq!(userfile.tackle_box.iter().map(|t| (t.name.as_str(), t.level)).collect::<Vec<_>>(), [(lines[0], 3)]);
+
+        // equipped tackle isn't in the box
+        assert!(!userfile.equip_tackle(lines[1], &tackle_data));
+    }
+
+    #[test]
+    fn missing_rods_fall_back() {
+        let rod_data = crate::data::rod_data();
+        let component_data = crate::data::component_data();
+        let tackle_data = crate::data::tackle_data();
+        let boat_data = crate::data::boat_data();
+        let skill_data = crate::data::skill_data();
+        let get_rod = |userfile: &UserValues| userfile.get_rod(&rod_data, &component_data, &tackle_data, &boat_data, &skill_data);
+        let crafted = CraftedRod {
+            blank: "Removed Blank".to_string(),
+            reel: String::new(),
+            line: String::new(),
+            hook: String::new(),
+        };
+        let userfile = UserValues { crafted_rod: Some(crafted), ..Default::default() };
+        assert_eq!(get_rod(&userfile).base.id, userfile.rod_id);
+
+        let userfile = UserValues { rod_id: "removed_rod".to_string(), ..Default::default() };
+        assert_eq!(get_rod(&userfile).base.id, UserValues::default().rod_id);
+    }
+
+    #[test]
+    fn first_prestige_counts_older_saves() {
+        let mut userfile = UserValues { version: 0, has_seen: vec!["Bass".to_string()], ..Default::default() };
+        userfile.migrate();
+        assert_eq!(userfile.seen_this_prestige, ["bass"]);
+    }
+
+    #[test]
+    fn full_rods_keep_their_modifiers() {
+        let mut userfile = full_rod();
+        userfile.claim_reward(&QuestReward::Modifier("sharp".to_string()), &crate::data::rod_data());
+
+        assert_eq!(userfile.rod_modifiers, full_rod().rod_modifiers);
+        assert_eq!(userfile.pending_modifiers, ["sharp"]);
+        assert!(matches!(userfile.fit_pending_modifier(0, None, &crate::data::rod_data()), Err(ModifierError::RodFull)));
+
+        userfile.fit_pending_modifier(0, Some(2), &crate::data::rod_data()).unwrap();
+        assert_eq!(userfile.rod_modifiers, ["masterwork", "upgraded", "sharp"]);
+        assert!(userfile.pending_modifiers.is_empty());
+    }
+
+    #[test]
+    fn drawbacks_wait_for_the_player() {
+        let mut userfile = UserValues::default();
+        userfile.claim_reward(&QuestReward::Modifier("old".to_string()), &crate::data::rod_data());
+        assert!(userfile.rod_modifiers.is_empty());
+
+        userfile.discard_pending_modifier(0, &crate::data::rod_data()).unwrap();
+        assert!(userfile.pending_modifiers.is_empty());
+    }
+}
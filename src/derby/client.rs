@@ -0,0 +1,202 @@
+use std::io::{BufReader, ErrorKind};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{SyncSender, TrySendError};
+use std::time::Duration;
+use super::protocol::{CatchReport, ClientMessage, FeedEntry, HostMessage, PROTOCOL_VERSION, READ_TIMEOUT, receive, send,
+                      spawn_writer, Standing, WRITE_TIMEOUT};
+
+/// the most feed entries kept for display
+const MAX_FEED: usize = 100;
+/// how long to wait for the host to answer before giving up on joining
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// What a player currently knows about the derby
+#[derive(Debug, Clone, Default)]
+pub struct DerbyView {
+    /// oldest first
+    pub feed: Vec<FeedEntry>,
+    pub leaderboard: Vec<Standing>,
+    /// set once the connection to the host is lost
+    pub disconnected: Option<String>,
+}
+
+/// A connection to a derby host
+pub struct DerbyClient {
+    pub name: String,
+    pub host: String,
+    /// messages for the host, written on their own thread
+    outbox: SyncSender<ClientMessage>,
+    view: Arc<Mutex<DerbyView>>,
+}
+
+impl DerbyClient {
+    /// Joins the derby at `host`, failing if it can't be reached or turns the player away
+    pub fn connect(host: &str, name: &str) -> std::io::Result<Self> {
+        let addr = host.to_socket_addrs()?.next()
+            .ok_or_else(|| std::io::Error::new(ErrorKind::NotFound, "That address doesn't point anywhere"))?;
+        let mut stream = TcpStream::connect_timeout(&addr, HANDSHAKE_TIMEOUT)?;
+        stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        send(&mut stream, &ClientMessage::Hello { version: PROTOCOL_VERSION, name: name.to_string() })?;
+        let view = match receive(&mut reader)? {
+            Some(HostMessage::Welcome { feed, leaderboard }) => DerbyView { feed, leaderboard, disconnected: None },
+            Some(HostMessage::Rejected { reason }) => return Err(std::io::Error::new(ErrorKind::ConnectionRefused, reason)),
+            _ => return Err(std::io::Error::new(ErrorKind::InvalidData, "The host didn't say hello")),
+        };
+        // the host pings while it has nothing to say, so silence means it's gone
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        let outbox = spawn_writer(stream, ClientMessage::Ping)?;
+        let view = Arc::new(Mutex::new(view));
+
+        // keep the view up to date with whatever the host sends
+        let listener_view = view.clone();
+        std::thread::spawn(move || {
+            let reason = loop {
+                match receive(&mut reader) {
+                    Ok(Some(message)) => apply(&mut listener_view.lock().unwrap(), message),
+                    Ok(None) => break "The host closed the derby".to_string(),
+                    Err(e) => break format!("Lost connection to the host: {}", e),
+                }
+            };
+            listener_view.lock().unwrap().disconnected = Some(reason);
+        });
+
+        Ok(Self {
+            name: name.to_string(),
+            host: host.to_string(),
+            outbox,
+            view,
+        })
+    }
+
+    /// Queues a catch to be sent to the host, never waiting on the connection
+    pub fn report_catch(&self, catch: CatchReport) -> std::io::Result<()> {
+        self.outbox.try_send(ClientMessage::Catch(catch)).map_err(|e| match e {
+            TrySendError::Full(_) => std::io::Error::new(ErrorKind::WouldBlock, "Too many catches are waiting to be sent"),
+            TrySendError::Disconnected(_) => std::io::Error::new(ErrorKind::NotConnected, "Lost connection to the host"),
+        })
+    }
+
+    pub fn view(&self) -> DerbyView {
+        self.view.lock().unwrap().clone()
+    }
+}
+
+impl Drop for DerbyClient {
+    fn drop(&mut self) {
+        // the host may already be gone, the writer hangs up after sending this
+        let _ = self.outbox.try_send(ClientMessage::Goodbye);
+    }
+}
+
+fn apply(view: &mut DerbyView, message: HostMessage) {
+    match message {
+        HostMessage::Feed(entry) => {
+            view.feed.push(entry);
+            if view.feed.len() > MAX_FEED {
+                view.feed.remove(0);
+            }
+        }
+        HostMessage::Leaderboard(leaderboard) => view.leaderboard = leaderboard,
+        HostMessage::Welcome { .. } | HostMessage::Rejected { .. } | HostMessage::Ping => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+    use super::*;
+    use crate::derby::host::Host;
+    use crate::derby::protocol::FeedEvent;
+
+    /// Starts a host on a free localhost port, returning its address
+    fn start_host() -> String {
+        let host = Host::bind("127.0.0.1:0").unwrap();
+        let addr = host.local_addr().unwrap().to_string();
+        std::thread::spawn(move || host.run());
+        addr
+    }
+
+    /// Waits for the client's view to match, the host relays messages on its own threads
+    fn wait_for(client: &DerbyClient, check: impl Fn(&DerbyView) -> bool) -> DerbyView {
+        let start = Instant::now();
+        loop {
+            let view = client.view();
+            if check(&view) {
+                return view;
+            }
+            assert!(start.elapsed() < Duration::from_secs(5), "timed out, view was {:?}", view);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn catch(species: &str, weight: f32, value: u64) -> CatchReport {
+        CatchReport {
+            species: species.to_string(),
+            rarity: "Common".to_string(),
+            weight,
+            value,
+        }
+    }
+
+    #[test]
+    fn catches_are_shared_between_players() {
+        let addr = start_host();
+        let alice = DerbyClient::connect(&addr, "Alice").unwrap();
+        let bob = DerbyClient::connect(&addr, "Bob").unwrap();
+        wait_for(&alice, |v| v.leaderboard.len() == 2);
+
+        alice.report_catch(catch("Bass", 4.5, 30)).unwrap();
+        bob.report_catch(catch("Pike", 9.0, 80)).unwrap();
+        bob.report_catch(catch("Perch", 1.0, 5)).unwrap();
+
+        let view = wait_for(&alice, |v| v.leaderboard.iter().map(|s| s.catches).sum::<u32>() == 3);
+        let names: Vec<&str> = view.leaderboard.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["Bob", "Alice"]);
+        assert_eq!(view.leaderboard[0].value, 85);
+        assert_eq!(view.leaderboard[0].heaviest, 9.0);
+        assert!(view.feed.iter().any(|e| e.player == "Bob" && e.event == FeedEvent::Caught(catch("Pike", 9.0, 80))));
+    }
+
+    #[test]
+    fn leaving_keeps_the_standing() {
+        let addr = start_host();
+        let alice = DerbyClient::connect(&addr, "Alice").unwrap();
+        let bob = DerbyClient::connect(&addr, "Bob").unwrap();
+        bob.report_catch(catch("Pike", 9.0, 80)).unwrap();
+        wait_for(&alice, |v| v.leaderboard.iter().any(|s| s.catches == 1));
+
+        drop(bob);
+        let view = wait_for(&alice, |v| v.feed.iter().any(|e| e.player == "Bob" && e.event == FeedEvent::Left));
+        let bob = view.leaderboard.iter().find(|s| s.name == "Bob").unwrap();
+        assert!(!bob.online);
+        assert_eq!(bob.value, 80);
+
+        // joining again picks the standing back up
+        let bob = DerbyClient::connect(&addr, "Bob").unwrap();
+        let view = wait_for(&bob, |v| v.leaderboard.iter().any(|s| s.name == "Bob" && s.online));
+        assert_eq!(view.leaderboard.iter().find(|s| s.name == "Bob").unwrap().catches, 1);
+    }
+
+    #[test]
+    fn duplicate_names_are_rejected() {
+        let addr = start_host();
+        let _alice = DerbyClient::connect(&addr, "Alice").unwrap();
+
+        let err = DerbyClient::connect(&addr, "Alice").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::ConnectionRefused);
+    }
+
+    #[test]
+    fn other_protocol_versions_are_rejected() {
+        let addr = start_host();
+        let mut stream = TcpStream::connect(&addr).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        send(&mut stream, &ClientMessage::Hello { version: PROTOCOL_VERSION + 1, name: "Alice".to_string() }).unwrap();
+        assert!(matches!(receive(&mut reader).unwrap(), Some(HostMessage::Rejected { .. })));
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::{HashMap, VecDeque};
+use std::io::BufReader;
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::SyncSender;
+use std::time::Duration;
+use chrono::Local;
+use crate::{nay, say};
+use super::protocol::{ClientMessage, FeedEntry, FeedEvent, HostMessage, PROTOCOL_VERSION, READ_TIMEOUT, receive, send,
+                      spawn_writer, Standing, WRITE_TIMEOUT};
+
+/// how many feed entries new players are sent when they join
+const FEED_HISTORY: usize = 50;
+/// the host gives up after this many connections in a row fail to be accepted
+const MAX_ACCEPT_FAILURES: u32 = 10;
+/// how long to wait after a failed accept, so a lasting problem doesn't spin
+const ACCEPT_RETRY: Duration = Duration::from_millis(100);
+
+/// An online player, written to by its own thread
+struct Connection {
+    /// tells this connection apart from a later one under the same name
+    id: u64,
+    outbox: SyncSender<HostMessage>,
+}
+
+#[derive(Default)]
+struct Derby {
+    /// player name -> connection, only for players that are online
+    connections: HashMap<String, Connection>,
+    next_connection: u64,
+    standings: Vec<Standing>,
+    feed: VecDeque<FeedEntry>,
+}
+
+impl Derby {
+    fn leaderboard(&self) -> Vec<Standing> {
+        let mut leaderboard = self.standings.clone();
+        leaderboard.sort_by(|a, b| b.value.cmp(&a.value).then(b.heaviest.total_cmp(&a.heaviest)));
+        leaderboard
+    }
+
+    fn standing_mut(&mut self, name: &str) -> &mut Standing {
+        if let Some(i) = self.standings.iter().position(|s| s.name == name) {
+            return &mut self.standings[i];
+        }
+
+        self.standings.push(Standing {
+            name: name.to_string(),
+            online: true,
+            catches: 0,
+            value: 0,
+            heaviest: 0.0,
+        });
+        self.standings.last_mut().unwrap()
+    }
+
+    /// Queues a message for every online player, dropping anyone whose connection is gone or too far behind
+    fn broadcast(&mut self, message: &HostMessage) {
+        let mut lost = Vec::new();
+        for (name, connection) in &self.connections {
+            if connection.outbox.try_send(message.clone()).is_err() {
+                lost.push(name.clone());
+            }
+        }
+        for name in lost {
+            self.connections.remove(&name);
+            self.standing_mut(&name).online = false;
+        }
+    }
+
+    /// Adds to the feed and sends it out along with the new leaderboard
+    fn publish(&mut self, player: &str, event: FeedEvent) {
+        let entry = FeedEntry {
+            player: player.to_string(),
+            event,
+            timestamp: Local::now().timestamp(),
+        };
+        say!("{}", entry);
+
+        self.feed.push_back(entry.clone());
+        if self.feed.len() > FEED_HISTORY {
+            self.feed.pop_front();
+        }
+
+        self.broadcast(&HostMessage::Feed(entry));
+        let leaderboard = self.leaderboard();
+        self.broadcast(&HostMessage::Leaderboard(leaderboard));
+    }
+}
+
+/// Accepts players on the LAN and relays their catches to each other
+pub struct Host {
+    listener: TcpListener,
+    derby: Arc<Mutex<Derby>>,
+}
+
+impl Host {
+    pub fn bind(addr: impl ToSocketAddrs) -> std::io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            derby: Arc::new(Mutex::new(Derby::default())),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serves players until the listener keeps failing, each on their own thread
+    pub fn run(self) -> std::io::Result<()> {
+        let mut failures = 0;
+        for stream in self.listener.incoming() {
+            // one player failing to connect shouldn't end the derby for everyone else
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    failures += 1;
+                    if failures >= MAX_ACCEPT_FAILURES {
+                        return Err(e);
+                    }
+                    nay!("Failed to accept a derby player: {}", e);
+                    std::thread::sleep(ACCEPT_RETRY);
+                    continue;
+                }
+            };
+            failures = 0;
+            let derby = self.derby.clone();
+            std::thread::spawn(move || {
+                if let Err(e) = serve_player(stream, &derby) {
+                    nay!("Derby connection error: {}", e);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+fn serve_player(stream: TcpStream, derby: &Mutex<Derby>) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    // only a missing hello is answered here, everything else goes through the writer
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+
+    let Some(ClientMessage::Hello { version, name }) = receive(&mut reader)? else {
+        return send(&mut writer, &HostMessage::Rejected { reason: "Expected a hello".to_string() });
+    };
+    let name = name.trim().to_string();
+
+    let outbox = spawn_writer(writer, HostMessage::Ping)?;
+    let id = {
+        let mut derby = derby.lock().unwrap();
+        let rejection = if version != PROTOCOL_VERSION {
+            Some(format!("The host is on protocol v{}, you are on v{}", PROTOCOL_VERSION, version))
+        } else if name.is_empty() {
+            Some("Pick a name first".to_string())
+        } else if derby.connections.contains_key(&name) {
+            Some(format!("{} is already fishing here", name))
+        } else {
+            None
+        };
+        // the writer hangs up once it has sent the rejection
+        if let Some(reason) = rejection {
+            let _ = outbox.try_send(HostMessage::Rejected { reason });
+            return Ok(());
+        }
+
+        // queued before anything else can be broadcast to them
+        let _ = outbox.try_send(HostMessage::Welcome {
+            feed: derby.feed.iter().cloned().collect(),
+            leaderboard: derby.leaderboard(),
+        });
+        let id = derby.next_connection;
+        derby.next_connection += 1;
+        derby.connections.insert(name.clone(), Connection { id, outbox });
+        derby.standing_mut(&name).online = true;
+        derby.publish(&name, FeedEvent::Joined);
+        id
+    };
+
+    // a broken or silent connection counts as leaving
+    loop {
+        match receive(&mut reader) {
+            Ok(Some(ClientMessage::Catch(catch))) => {
+                let mut derby = derby.lock().unwrap();
+                let standing = derby.standing_mut(&name);
+                standing.catches += 1;
+                standing.value = standing.value.saturating_add(catch.value);
+                standing.heaviest = standing.heaviest.max(catch.weight);
+                derby.publish(&name, FeedEvent::Caught(catch));
+            }
+            // a second hello is ignored
+            Ok(Some(ClientMessage::Hello { .. })) | Ok(Some(ClientMessage::Ping)) => {}
+            Ok(Some(ClientMessage::Goodbye)) | Ok(None) | Err(_) => break,
+        }
+    }
+
+    let mut derby = derby.lock().unwrap();
+    // they may have already rejoined on a new connection
+    if derby.connections.get(&name).is_some_and(|connection| connection.id != id) {
+        return Ok(());
+    }
+    derby.connections.remove(&name);
+    derby.standing_mut(&name).online = false;
+    derby.publish(&name, FeedEvent::Left);
+
+    Ok(())
+}
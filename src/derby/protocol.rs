@@ -0,0 +1,134 @@
+use std::fmt::Display;
+use std::io::{BufRead, Write};
+use std::net::{Shutdown, TcpStream};
+use std::sync::mpsc::{RecvTimeoutError, sync_channel, SyncSender};
+use std::time::Duration;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// bumped whenever a message changes shape, hosts turn away clients on a different version
+pub const PROTOCOL_VERSION: u32 = 2;
+pub const DEFAULT_PORT: u16 = 7878;
+/// how often each side pings when it has nothing else to send
+pub const HEARTBEAT: Duration = Duration::from_secs(10);
+/// a connection that sends nothing, not even a ping, for this long is treated as lost
+pub const READ_TIMEOUT: Duration = Duration::from_secs(30);
+/// a write stuck this long means the other side has stopped reading
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+/// messages waiting to be written before the connection is given up on
+const OUTBOX_SIZE: usize = 256;
+
+/// A fish one of the players landed
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CatchReport {
+    pub species: String,
+    pub rarity: String,
+    pub weight: f32,
+    /// what the fish sold for, in dollars
+    pub value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum FeedEvent {
+    Joined,
+    Left,
+    Caught(CatchReport),
+}
+
+/// One line of the shared live feed
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct FeedEntry {
+    pub player: String,
+    pub event: FeedEvent,
+    pub timestamp: i64,
+}
+
+impl Display for FeedEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.event {
+            FeedEvent::Joined => write!(f, "{} joined the derby", self.player),
+            FeedEvent::Left => write!(f, "{} left the derby", self.player),
+            FeedEvent::Caught(catch) => write!(f, "{} caught a {}lb {} {} (${})",
+                                               self.player, catch.weight, catch.rarity, catch.species, catch.value),
+        }
+    }
+}
+
+/// A player's totals on the shared leaderboard
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Standing {
+    pub name: String,
+    /// false once the player disconnects, their catches still count
+    pub online: bool,
+    pub catches: u32,
+    pub value: u64,
+    pub heaviest: f32,
+}
+
+/// Sent from a player to the host
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum ClientMessage {
+    /// must be the first message on a connection
+    Hello { version: u32, name: String },
+    Catch(CatchReport),
+    /// keeps an idle connection from timing out
+    Ping,
+    Goodbye,
+}
+
+/// Sent from the host to the players
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum HostMessage {
+    /// the reply to an accepted `Hello`, with everything that happened so far
+    Welcome { feed: Vec<FeedEntry>, leaderboard: Vec<Standing> },
+    /// the reply to a refused `Hello`, the host closes the connection after sending it
+    Rejected { reason: String },
+    Feed(FeedEntry),
+    /// sorted from first place to last
+    Leaderboard(Vec<Standing>),
+    /// keeps an idle connection from timing out
+    Ping,
+}
+
+/// Writes a message as a single line of JSON
+pub fn send<T: Serialize>(writer: &mut impl Write, message: &T) -> std::io::Result<()> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    writer.write_all(line.as_bytes())?;
+    writer.flush()
+}
+
+/// Reads the next message, `None` once the other side has hung up
+pub fn receive<T: DeserializeOwned>(reader: &mut impl BufRead) -> std::io::Result<Option<T>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_str(&line)?))
+}
+
+/// Writes messages queued on the returned sender from a thread of its own, so nobody waits on
+/// a slow connection. Sends `ping` whenever nothing is queued for a `HEARTBEAT`, and shuts the
+/// stream down once a write fails or the sender is dropped.
+pub fn spawn_writer<T: Serialize + Clone + Send + 'static>(mut stream: TcpStream, ping: T) -> std::io::Result<SyncSender<T>> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let (outbox, queued) = sync_channel::<T>(OUTBOX_SIZE);
+
+    std::thread::spawn(move || {
+        loop {
+            let sent = match queued.recv_timeout(HEARTBEAT) {
+                Ok(message) => send(&mut stream, &message),
+                Err(RecvTimeoutError::Timeout) => send(&mut stream, &ping),
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+            if sent.is_err() {
+                break;
+            }
+        }
+        // wakes up whoever is reading the other half
+        let _ = stream.shutdown(Shutdown::Both);
+    });
+
+    Ok(outbox)
+}
@@ -3,41 +3,159 @@
 mod data;
 pub mod logging;
 mod catch_handler;
+mod derby;
+mod online;
+mod charts;
 
+use std::fmt::Display;
 use std::sync::{Arc, Mutex};
 use std::thread;
-use chrono::{Duration, Local};
-use egui::{Context, IconData, Response, SidePanel, Ui, Vec2};
-use rand::{Rng, thread_rng};
-use crate::catch_handler::CatchData;
-use crate::data::fish::{Fish, FishData};
-use crate::data::{fish_data, rod_data};
-use crate::data::rods::{BaseRod, Rod, RodData};
-use crate::data::shop::Shop;
-use crate::data::userfile::read_userfile;
-
-const WEIGHT_ADD_TIME: f32 = 0.05;
+use chrono::{Local, NaiveDateTime};
+use egui::{Color32, Context, IconData, Response, SidePanel, Ui, Vec2};
+use crate::catch_handler::{AUTO_FISHER_EFFICIENCY, CatchData, OfflineSummary};
+use crate::data::fish::{FishData, FishRarity};
+use crate::data::history::{CatchOutcome, CatchRecord, export_history, ExportFormat, history_len, HistoryFilter, HistoryRange, read_history};
+use crate::data::{achievement_data, boat_data, collection_data, component_data, event_data, fish_data, loot_data, rod_data, skill_data, tackle_data, tournament_data};
+use crate::data::aquarium::{keep_fish, MAX_SLOTS, sell_kept_fish, upgrade_tank};
+use crate::data::boats::BoatData;
+use crate::data::tackle::{TackleData, TackleSlot};
+use crate::data::components::{ComponentData, ComponentKind, craft_rod, CraftedRod, RodComponent};
+use crate::data::skills::{level_for_xp, SkillData, xp_to_next_level};
+use crate::data::achievements::AchievementData;
+use crate::data::collections::CollectionData;
+use crate::data::events::EventData;
+use crate::data::loot::{LootData, LootKind, open_treasure, recycle};
+use crate::data::money::Money;
+use crate::data::rods::{BaseRod, MAX_MODIFIERS, Rod, RodData};
+use crate::data::shop::{AUTO_FISHER_COST, Shop};
+use crate::data::stats::Stats;
+use crate::data::tournaments::{ActiveTournament, enter_tournament, Tournament, TournamentData};
+use crate::data::prestige::{discounted, prestige, prestige_requirements};
+use crate::data::quests::QuestKind;
+use crate::data::userfile::{read_userfile, update_userfile, UserValues};
+use crate::derby::client::DerbyClient;
+use crate::derby::host::Host;
+use crate::derby::protocol::DEFAULT_PORT;
+use crate::online::client::{OnlineConfig, read_config, update_config, Uploader};
+
+const MAX_HISTORY_SHOWN: usize = 200;
+
+/// The views the navigation buttons open
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Panel {
+    Shop,
+    Bestiary,
+    Achievements,
+    Quests,
+    History,
+    Stats,
+    Skills,
+    Workshop,
+    Aquarium,
+    Tournaments,
+    Derby,
+    Online,
+}
+
+impl Panel {
+    fn all() -> [Self; 12] {
+        [Self::Shop, Self::Bestiary, Self::Achievements, Self::Quests, Self::History, Self::Stats, Self::Skills, Self::Workshop, Self::Aquarium, Self::Tournaments, Self::Derby, Self::Online]
+    }
+
+    fn hover_text(&self) -> &'static str {
+        match self {
+            Panel::Shop => "Click to view the shop!",
+            Panel::Bestiary => "Click to view the bestiary!",
+            Panel::Achievements => "Click to view your achievements!",
+            Panel::Quests => "Click to view the quest board!",
+            Panel::History => "Click to view your catch history!",
+            Panel::Stats => "Click to view your statistics!",
+            Panel::Skills => "Click to view your skill tree!",
+            Panel::Workshop => "Click to craft your own rod!",
+            Panel::Aquarium => "Click to view your aquarium!",
+            Panel::Tournaments => "Click to compete in a fishing tournament!",
+            Panel::Derby => "Click to fish with friends on your network!",
+            Panel::Online => "Click to submit your scores to the online leaderboard!",
+        }
+    }
+}
+
+impl Display for Panel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Panel::Shop => write!(f, "Shop"),
+            Panel::Bestiary => write!(f, "Bestiary"),
+            Panel::Achievements => write!(f, "Achievements"),
+            Panel::Quests => write!(f, "Quests"),
+            Panel::History => write!(f, "History"),
+            Panel::Stats => write!(f, "Stats"),
+            Panel::Skills => write!(f, "Skills"),
+            Panel::Workshop => write!(f, "Workshop"),
+            Panel::Aquarium => write!(f, "Aquarium"),
+            Panel::Tournaments => write!(f, "Tournaments"),
+            Panel::Derby => write!(f, "Derby"),
+            Panel::Online => write!(f, "Online"),
+        }
+    }
+}
 
 struct MiniFisher {
     title: String,
 
-    show_shop: bool,
-    shop_button_content: String,
-    show_bestiary: bool,
-    bestiary_button_content: String,
+    /// the panel shown in place of the main view, only one is open at a time
+    panel: Option<Panel>,
+    /// shows loot items instead of fish in the bestiary
+    bestiary_items: bool,
 
     rod_data: RodData,
     fish_data: FishData,
+    achievement_data: AchievementData,
+    collection_data: CollectionData,
+    event_data: EventData,
+    loot_data: LootData,
+    skill_data: SkillData,
+    component_data: ComponentData,
+    tackle_data: TackleData,
+    boat_data: BoatData,
+    tournament_data: TournamentData,
 
     catch_data_ref: Arc<Mutex<CatchData>>,
 
     dark_theme: bool,
 
     cached_catch_data: Option<CatchData>,
+
+    history: Vec<CatchRecord>,
+    history_len: u64,
+    history_filter: HistoryFilter,
+    history_message: String,
+    /// the outcome of the last reforge, shown under the button
+    reforge_message: String,
+    stats: Stats,
+
+    offline_summary: Option<OfflineSummary>,
+    confirm_prestige: bool,
+    confirm_sell_boat: bool,
+    confirm_craft: bool,
+    /// the rod whose buy button was clicked once, buying it replaces the crafted rod
+    confirm_buy_rod: Option<String>,
+    /// selected component names, in `ComponentKind::all()` order
+    craft_selection: [Option<String>; 4],
+    derby_name: String,
+    /// the host to join, or the port to host on
+    derby_address: String,
+    /// edited in the online view, saved when applied
+    online_config: OnlineConfig,
+    /// the config as it was last saved, so edits can be told apart without reading it every frame
+    saved_online_config: OnlineConfig,
+    uploader: Uploader,
 }
 
 impl MiniFisher {
     fn new(title: String) -> Self {
+        let userfile = read_userfile();
+        let auto_fish = userfile.auto_fisher && userfile.auto_fish_enabled;
+
         let catch_data = Arc::new(Mutex::new(CatchData {
             ctx: None,
             cast: false,
@@ -48,86 +166,127 @@ impl MiniFisher {
             fish: None,
             will_catch: false,
 
-            was_turtle: false,
+            event_image: None,
             caught: false,
+            last_catch: None,
+
+            auto_fish,
+            derby: None,
+            derby_joining: false,
+            derby_hosting: None,
+            catching_up: true,
+            offline_summary: None,
+            tournament_ends_at: None,
 
             running: true,
         }));
         let catch_data_ref = catch_data.clone();
 
         thread::spawn(move || {
+            // catch up on anything that happened while the game was closed, before casting again
+            catch_handler::catch_up(&catch_data);
             catch_handler::schedule(catch_data);
         });
+        let online_config = read_config();
 
         Self {
             title,
-            show_shop: false,
-            shop_button_content: "Shop >".to_string(),
-            show_bestiary: false,
-            bestiary_button_content: "Bestiary >".to_string(),
+            panel: None,
+            bestiary_items: false,
 
             rod_data: rod_data(),
             fish_data: fish_data(),
+            achievement_data: achievement_data(),
+            collection_data: collection_data(),
+            event_data: event_data(),
+            loot_data: loot_data(),
+            skill_data: skill_data(),
+            component_data: component_data(),
+            tackle_data: tackle_data(),
+            boat_data: boat_data(),
+            tournament_data: tournament_data(),
 
             catch_data_ref,
 
             dark_theme: true,
 
             cached_catch_data: None,
+
+            history: Vec::new(),
+            history_len: 0,
+            history_filter: HistoryFilter::default(),
+            history_message: String::new(),
+            reforge_message: String::new(),
+            stats: Stats::default(),
+
+            offline_summary: None,
+            confirm_prestige: false,
+            confirm_sell_boat: false,
+            confirm_craft: false,
+            confirm_buy_rod: None,
+            craft_selection: Default::default(),
+            derby_name: "Angler".to_string(),
+            derby_address: format!("127.0.0.1:{}", DEFAULT_PORT),
+            saved_online_config: online_config.clone(),
+            online_config,
+            uploader: Uploader::start(fish_data()),
         }
     }
 
+    /// The player's rod, built from the catalogs loaded with the app
+    fn rod(&self, userfile: &UserValues) -> Rod {
+        userfile.get_rod(&self.rod_data, &self.component_data, &self.tackle_data, &self.boat_data, &self.skill_data)
+    }
+
     fn cast_rod(&mut self) {
         let mut catch_data = self.catch_data_ref.lock().unwrap();
 
-        // get the user file
-        let user_file = read_userfile();
-
-        let rod = user_file.get_rod(&self.rod_data);
-
-        catch_data.display_text = format!("You cast your {}!", rod);
-
-        // get the fish
-        let fish = Fish::random_fish(&self.fish_data, &rod);
-
-        catch_data.cast = true;
-        catch_data.caught = false;
-        catch_data.cast_btn_txt = "Rod is cast!".to_string();
-
-        // set the cast time
-        catch_data.cast_time = Some(Local::now());
-
-        // set the cast duration
-        let weight_catch_time_add = (fish.weight - fish.fish_type.avg_weight as f32) * WEIGHT_ADD_TIME;
-        let duration = (rod.random_catch_time() + weight_catch_time_add) as i64;
-
-        catch_data.cast_duration = Some(Duration::seconds(duration));
-
-        catch_data.will_catch = thread_rng().gen_range(0..1000) <= (rod.get_catch_chance());
-
-        catch_data.fish = Some(fish);
+        let rod = self.rod(&read_userfile());
+        catch_handler::start_cast(&mut catch_data, &rod, &self.fish_data, 1.0);
 
         //say!("Cast | will catch: {} | duration: {}s", catch_data.will_catch, duration);
     }
 
+    /// reloads the catch history if the log has changed since it was last read
+    fn refresh_history(&mut self) {
+        let len = history_len();
+        if len != self.history_len {
+            self.history = read_history();
+            self.history_len = len;
+            self.stats = Stats::compute(&self.history);
+        }
+    }
+
     fn exit(&mut self) {
         let mut catch_data = self.catch_data_ref.lock().unwrap();
         catch_data.running = false;
+
+        let mut userfile = read_userfile();
+        userfile.last_seen = Local::now().timestamp();
+        update_userfile(userfile);
+    }
+
+    fn set_auto_fish(&mut self, enabled: bool) {
+        self.catch_data_ref.lock().unwrap().auto_fish = enabled;
+
+        let mut userfile = read_userfile();
+        userfile.auto_fish_enabled = enabled;
+        userfile.last_seen = Local::now().timestamp();
+        update_userfile(userfile);
     }
 
     fn generate_navigation_buttons(&mut self, ui: &mut Ui, theme_btn_text: &str) {
-        ui.horizontal(|ui| {
+        ui.horizontal_wrapped(|ui| {
             let theme_button = ui.button(theme_btn_text).on_hover_text("Click to change theme!");
             if theme_button.clicked() {
                 self.dark_theme = !self.dark_theme;
             }
-            let shop_button = ui.button(self.shop_button_content.clone()).on_hover_text("Click to view the shop!");
-            if shop_button.clicked() {
-                self.show_shop = !self.show_shop;
-            }
-            let bestiary_button = ui.button(self.bestiary_button_content.clone()).on_hover_text("Click to view the bestiary!");
-            if bestiary_button.clicked() {
-                self.show_bestiary = !self.show_bestiary;
+            for panel in Panel::all() {
+                let open = self.panel == Some(panel);
+                let button = ui.button(format!("{} {}", panel, if open { ">" } else { "<" })).on_hover_text(panel.hover_text());
+                if button.clicked() {
+                    self.panel = if open { None } else { Some(panel) };
+                }
             }
         });
     }
@@ -157,41 +316,160 @@ impl eframe::App for MiniFisher {
             }
         };
 
-        egui::CentralPanel::default().show(ctx, |ui| {
-            self.shop_button_content = if self.show_shop {
-                String::from("Shop >")
-            } else {
-                String::from("Shop <")
-            };
-
-            self.bestiary_button_content = if self.show_bestiary {
-                String::from("Bestiary >")
-            } else {
-                String::from("Bestiary <")
-            };
+        // the scheduler hands over the offline summary once it's done simulating
+        if catch_data.offline_summary.is_some() {
+            self.offline_summary = self.catch_data_ref.lock().unwrap().offline_summary.take();
+        }
+        if catch_data.catching_up {
+            ctx.request_repaint_after(std::time::Duration::from_millis(250));
+        }
 
+        egui::CentralPanel::default().show(ctx, |ui| {
             // Show/hide side panel based on button click
-            if self.show_shop {
+            if self.panel == Some(Panel::Shop) {
                 let mut shop = Shop::load(&self.rod_data);
 
                 ui.vertical(|ui| {
                     ui.spacing_mut().item_spacing.y = 10.0;
                     self.generate_navigation_buttons(ui, theme_btn_text);
-                    let userfile = read_userfile();
-                    ui.heading("Balance:");
-                    ui.label(format!("${}", userfile.money));
-                    let rod = userfile.get_rod(&self.rod_data);
-                    ui.heading("Your Rod:");
-                    let rod_name = rod.to_string();
-                    let rod_name = if rod_name.len() > 25 {
-                        let mut rod_name = rod_name.split_at(20).0.to_string();
-                        rod_name.push_str("...");
-                        rod_name
-                    } else {
-                        rod_name
-                    };
-                    let rod_label = ui.label(format!("{}\n(hover for more info)", rod_name));
-                    add_hover_txt_mod(rod_label, &rod);
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        let userfile = read_userfile();
+                        ui.heading("Balance:");
+                        ui.label(format!("{}", userfile.money));
+                        let rod = self.rod(&userfile);
+                        ui.heading("Your Rod:");
+                        let rod_name = rod.to_string();
+                        let rod_name = if rod_name.len() > 25 {
+                            let mut rod_name = rod_name.split_at(20).0.to_string();
+                            rod_name.push_str("...");
+                            rod_name
+                        } else {
+                            rod_name
+                        };
+                        let rod_label = ui.label(format!("{}\n(hover for more info)", rod_name));
+                        add_hover_txt_breakdown(rod_label, &rod);
+                        let reforge_cost = rod.base.rarity.reforge_cost().map(|cost| discounted(cost, rod.bonuses.shop_discount));
+                        let reforge_button = match reforge_cost {
+                            Some(cost) => egui::Button::new(format!("Reforge ({})", cost)),
+                            None => egui::Button::new("Reforge"),
+                        };
+                        let reforge_button_ui = ui.add_enabled(reforge_cost.is_some_and(|cost| userfile.money >= cost), reforge_button)
+                            .on_hover_text("Reroll all of your rod's modifiers!")
+                            .on_disabled_hover_text(if reforge_cost.is_none() { "The shop can't reforge this rod!" } else { "You can't afford to reforge your rod!" });
+                        if reforge_button_ui.clicked() {
+                            self.reforge_message = match Shop::reforge_rod(&self.rod_data) {
+                                Ok(msg) => {
+                                    yay!("{}", msg);
+                                    msg
+                                }
+                                Err(e) => {
+                                    say!("Failed to reforge rod: {}", e);
+                                    e.to_string()
+                                }
+                            };
+                        }
+                        if !self.reforge_message.is_empty() {
+                            ui.label(self.reforge_message.clone());
+                        }
+
+                        // modifiers won from quests and treasure, the player picks what they replace
+                        if !userfile.pending_modifiers.is_empty() {
+                            ui.heading("Unfitted Modifiers:");
+                        }
+                        for (i, id) in userfile.pending_modifiers.iter().enumerate() {
+                            let Some(modifier) = self.rod_data.get_modifier(id) else {
+                                continue;
+                            };
+                            ui.label(format!("{} ({})", modifier, modifier.tier));
+                            ui.horizontal_wrapped(|ui| {
+                                let mut choice = None;
+                                if userfile.rod_modifiers.len() < MAX_MODIFIERS {
+                                    if ui.button("Fit").clicked() {
+                                        choice = Some(None);
+                                    }
+                                } else {
+                                    for (slot, current) in userfile.rod_modifiers.iter().enumerate() {
+                                        if ui.button(format!("Replace {}", self.rod_data.modifier_name(current))).clicked() {
+                                            choice = Some(Some(slot));
+                                        }
+                                    }
+                                }
+                                let discard = ui.button("Discard").clicked();
+
+                                if choice.is_none() && !discard {
+                                    return;
+                                }
+                                let mut userfile = read_userfile();
+                                let result = match choice {
+                                    Some(replace) => userfile.fit_pending_modifier(i, replace, &self.rod_data),
+                                    None => userfile.discard_pending_modifier(i, &self.rod_data),
+                                };
+                                match result {
+                                    Ok(msg) => {
+                                        yay!("{}", msg);
+                                        update_userfile(userfile);
+                                    }
+                                    Err(e) => say!("{}", e),
+                                }
+                            });
+                        }
+                        if !userfile.auto_fisher {
+                            let auto_fisher_cost = discounted(AUTO_FISHER_COST, rod.bonuses.shop_discount);
+                            let auto_fisher_button = egui::Button::new(format!("Auto-Fisher ({})", auto_fisher_cost));
+                            let auto_fisher_button_ui = ui.add_enabled(userfile.money >= auto_fisher_cost, auto_fisher_button)
+                                .on_hover_text(format!("Keeps casting for you, even while the game is closed, at {}% catch chance!",
+                                                       (AUTO_FISHER_EFFICIENCY * 100.0) as u32));
+                            if auto_fisher_button_ui.clicked() {
+                                match Shop::buy_auto_fisher() {
+                                    Ok(msg) => yay!("{}", msg),
+                                    Err(e) => say!("Failed to buy auto-fisher: {:?}", e),
+                                }
+                            }
+                        }
+
+                        // boat
+                        ui.heading("Your Boat:");
+                        match userfile.boat.as_ref().and_then(|b| self.boat_data.get_boat(b)) {
+                            Some(boat) if userfile.get_boat(&self.boat_data).is_some() =>
+                                ui.label(format!("{}\n({} upkeep a day)", boat, boat.upkeep)),
+                            Some(boat) => ui.label(format!("{}\n(docked, {} upkeep overdue)", boat, boat.upkeep)),
+                            None => ui.label("None"),
+                        };
+                        if let Some(boat) = userfile.boat.as_ref().and_then(|b| self.boat_data.get_boat(b)) {
+                            let sell_text = if self.confirm_sell_boat { "Are you sure?".to_string() } else { format!("Sell for {}", boat.resale_value()) };
+                            let sell_button_ui = ui.button(sell_text).on_hover_text("Sell your boat back to the shop to stop paying upkeep");
+                            if sell_button_ui.clicked() {
+                                if self.confirm_sell_boat {
+                                    match Shop::sell_boat(&self.boat_data) {
+                                        Ok(msg) => yay!("{}", msg),
+                                        Err(e) => say!("Failed to sell boat: {}", e),
+                                    }
+                                    self.confirm_sell_boat = false;
+                                } else {
+                                    self.confirm_sell_boat = true;
+                                }
+                            }
+                        }
+
+                        // prestige
+                        ui.heading(format!("Prestige: {}", userfile.prestige));
+                        let requirements = prestige_requirements(&userfile, &self.fish_data, &rod.base.rarity);
+                        let prestige_text = if self.confirm_prestige { "Are you sure?" } else { "Prestige" };
+                        let prestige_button_ui = ui.add_enabled(requirements.is_none(), egui::Button::new(prestige_text))
+                            .on_hover_text("Reset your money and rod for permanent bonuses and exclusive fish and rods! The next prestige needs the bestiary filled in again.")
+                            .on_disabled_hover_text(requirements.unwrap_or_default());
+                        if prestige_button_ui.clicked() {
+                            if self.confirm_prestige {
+                                let mut userfile = read_userfile();
+                                prestige(&mut userfile);
+                                yay!("You are now prestige {}!", userfile.prestige);
+                                update_userfile(userfile);
+                                self.confirm_prestige = false;
+                            } else {
+                                self.confirm_prestige = true;
+                            }
+                        }
+                    });
                 });
 
                 SidePanel::right("shop")
@@ -206,30 +484,190 @@ impl eframe::App for MiniFisher {
                                 ui.label("Hover over an item's buy button to see more information!");
                                 ui.label(format!("Next restock in: {}", shop.get_time_until_restock()));
 
+                                let userfile = read_userfile();
+                                let discount = userfile.get_bonuses(&self.skill_data).shop_discount;
+
+                                // buying any rod takes the crafted rod apart for good
+                                let crafted = userfile.crafted_rod.is_some();
+                                if crafted {
+                                    ui.colored_label(Color32::YELLOW, "Buying a rod replaces your crafted rod, its components won't come back!");
+                                }
+
                                 for x in 0..shop.rods.len() {
-                                    let rod = &shop.rods[x];
-                                    let rod = self.rod_data.get_base_by_name(rod).unwrap();
+                                    let rod = self.rod_data.get_base(&shop.rods[x]).unwrap();
+                                    let cost = discounted(rod.cost, discount);
+
+                                    let confirming = self.confirm_buy_rod.as_ref() == Some(&rod.id);
+                                    if add_shop_entry(ui, rod, format!("{}", cost), userfile.money >= cost, confirming)
+                                        && confirm_rod_purchase(&mut self.confirm_buy_rod, crafted, &rod.id) {
+                                        let buy_result = shop.sell_rod(x, &self.rod_data);
+                                        if let Err(e) = buy_result {
+                                            say!("Failed to buy rod: {:?}", e);
+                                        }
+                                    }
+                                }
+
+                                // rod components
+                                if !shop.components.is_empty() {
+                                    ui.heading("Rod Components");
+                                }
+                                for x in 0..shop.components.len() {
+                                    let Some(component) = self.component_data.get_component_by_name(&shop.components[x]) else {
+                                        continue;
+                                    };
+                                    let cost = discounted(component.cost, discount);
 
                                     egui::Frame::group(ui.style()).show(ui, |ui| {
                                         ui.vertical(|ui| {
-                                            ui.heading(rod.name.clone());
-                                            let desc = ui.label(format!("{}\n(Hover for more information)", rod.description));
-                                            add_hover_txt(desc, &rod);
-                                            ui.label(format!("${}", rod.cost));
+                                            ui.heading(component.name.clone());
+                                            let desc = ui.label(format!("{} for crafting rods\n(Hover for more information)", component.kind));
+                                            add_hover_txt_component(desc, component);
+                                            ui.label(format!("{}", cost));
                                         });
                                         let buy_button = egui::Button::new("Buy");
-                                        let has_money = read_userfile().money >= rod.cost as u32;
-                                        let buy_button_ui = ui.add_enabled(has_money, buy_button);
+                                        let buy_button_ui = ui.add_enabled(userfile.money >= cost, buy_button);
                                         if buy_button_ui.clicked() {
-                                            let buy_result = shop.sell_rod(x, &self.rod_data);
-                                            if let Err(e) = buy_result {
-                                                say!("Failed to buy rod: {:?}", e);
+                                            match shop.sell_component(x, &self.component_data) {
+                                                Ok(msg) => yay!("{}", msg),
+                                                Err(e) => say!("Failed to buy component: {:?}", e),
+                                            }
+                                        }
+                                        add_hover_txt_component(buy_button_ui, component);
+                                    });
+                                }
+
+                                // tackle
+                                ui.heading("Tackle");
+                                ui.label("Tackle stays equipped when you change rods, and keeps its upgrades in your tackle box when swapped out!");
+                                for slot in TackleSlot::all() {
+                                    let equipped = userfile.tackle.iter()
+                                        .find(|t| self.tackle_data.get_tackle_by_name(&t.name).is_some_and(|tackle| tackle.slot == slot));
+
+                                    for tackle in self.tackle_data.tackle.iter().filter(|t| t.slot == slot) {
+                                        let level = equipped.filter(|t| t.name == tackle.name).map(|t| t.level);
+                                        let boxed_level = userfile.tackle_box.iter().find(|t| t.name == tackle.name).map(|t| t.level);
+
+                                        egui::Frame::group(ui.style()).show(ui, |ui| {
+                                            ui.vertical(|ui| {
+                                                ui.heading(format!("{} ({})", tackle.name, slot));
+                                                match (level, boxed_level) {
+                                                    (Some(level), _) => ui.label(format!("Equipped, level {}/{}: {}",
+                                                                                         level, tackle.max_level, tackle.describe(level))),
+                                                    (None, Some(level)) => ui.label(format!("In your tackle box, level {}/{}: {}",
+                                                                                            level, tackle.max_level, tackle.describe(level))),
+                                                    (None, None) => ui.label(format!("{}\n{}", tackle.describe(1), discounted(tackle.cost, discount))),
+                                                };
+                                            });
+                                            let replaces = equipped.map_or(format!("Fills your empty {} slot", slot.to_string().to_lowercase()),
+                                                                          |t| format!("Your {} goes into your tackle box", t.name));
+                                            match (level, boxed_level) {
+                                                (Some(level), _) if level < tackle.max_level => {
+                                                    let cost = discounted(tackle.upgrade_cost(level), discount);
+                                                    let upgrade_button = egui::Button::new(format!("Upgrade ({})", cost));
+                                                    let upgrade_button_ui = ui.add_enabled(userfile.money >= cost, upgrade_button)
+                                                        .on_hover_text(format!("Next level: {}", tackle.describe(level + 1)));
+                                                    if upgrade_button_ui.clicked() {
+                                                        match Shop::upgrade_tackle(&tackle.name, &self.tackle_data) {
+                                                            Ok(msg) => yay!("{}", msg),
+                                                            Err(e) => say!("Failed to upgrade tackle: {:?}", e),
+                                                        }
+                                                    }
+                                                }
+                                                (Some(_), _) => {}
+                                                (None, Some(_)) => {
+                                                    let equip_button_ui = ui.button("Equip").on_hover_text(replaces);
+                                                    if equip_button_ui.clicked() {
+                                                        match Shop::equip_tackle(&tackle.name, &self.tackle_data) {
+                                                            Ok(msg) => yay!("{}", msg),
+                                                            Err(e) => say!("Failed to equip tackle: {}", e),
+                                                        }
+                                                    }
+                                                }
+                                                (None, None) => {
+                                                    let cost = discounted(tackle.cost, discount);
+                                                    let buy_button = egui::Button::new("Buy");
+                                                    let buy_button_ui = ui.add_enabled(userfile.money >= cost, buy_button)
+                                                        .on_hover_text(replaces);
+                                                    if buy_button_ui.clicked() {
+                                                        match Shop::sell_tackle(&tackle.name, &self.tackle_data) {
+                                                            Ok(msg) => yay!("{}", msg),
+                                                            Err(e) => say!("Failed to buy tackle: {:?}", e),
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        });
+                                    }
+                                }
+
+                                // boats
+                                ui.heading("Boats");
+                                ui.label("Boats reach deeper water and new locations, but cost upkeep every day!");
+                                let owned_tier = userfile.boat.as_ref()
+                                    .and_then(|b| self.boat_data.get_boat(b))
+                                    .map(|b| b.tier)
+                                    .unwrap_or(0);
+                                for boat in self.boat_data.boats.iter() {
+                                    let cost = discounted(boat.cost, discount);
+
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.heading(boat.to_string());
+                                            ui.label(format!("{}\n+{}ft depth | {} upkeep a day\n{}",
+                                                             boat.description, boat.depth, boat.upkeep, cost));
+                                        });
+                                        let buy_button = egui::Button::new(if boat.tier <= owned_tier { "Owned" } else { "Buy" });
+                                        let buy_button_ui = ui.add_enabled(boat.tier > owned_tier && userfile.money >= cost, buy_button)
+                                            .on_hover_text(format!("Unlocks tier {} locations", boat.tier));
+                                        if buy_button_ui.clicked() {
+                                            match Shop::buy_boat(&boat.id, &self.boat_data) {
+                                                Ok(msg) => yay!("{}", msg),
+                                                Err(e) => say!("Failed to buy boat: {:?}", e),
                                             }
                                         }
-                                        add_hover_txt(buy_button_ui, &rod);
                                     });
                                 }
 
+                                // prestige exclusive rods
+                                let prestige_rods: Vec<&BaseRod> = self.rod_data.rods.iter()
+                                    .filter(|r| r.prestige > 0 && r.prestige <= userfile.prestige)
+                                    .collect();
+                                if !prestige_rods.is_empty() {
+                                    ui.heading("Prestige Rods");
+                                }
+                                for rod in prestige_rods {
+                                    let cost = discounted(rod.cost, discount);
+
+                                    let confirming = self.confirm_buy_rod.as_ref() == Some(&rod.id);
+                                    if add_shop_entry(ui, rod, format!("{}", cost), userfile.money >= cost, confirming)
+                                        && confirm_rod_purchase(&mut self.confirm_buy_rod, crafted, &rod.id) {
+                                        if let Err(e) = Shop::sell_prestige_rod(&rod.id, &self.rod_data) {
+                                            say!("Failed to buy rod: {:?}", e);
+                                        }
+                                    }
+                                }
+
+                                // themed stock for the running events
+                                for event in self.event_data.active() {
+                                    let balance = userfile.event_currency.get(&event.currency).copied().unwrap_or(0);
+                                    ui.heading(format!("{} Rods", event.name));
+                                    ui.label(format!("{}: {}", event.currency, balance));
+
+                                    for (id, price) in &event.shop {
+                                        let Some(rod) = self.rod_data.get_base(id) else {
+                                            continue;
+                                        };
+
+                                        let confirming = self.confirm_buy_rod.as_ref() == Some(&rod.id);
+                                        if add_shop_entry(ui, rod, format!("{} {}", price, event.currency), balance >= *price, confirming)
+                                            && confirm_rod_purchase(&mut self.confirm_buy_rod, crafted, &rod.id) {
+                                            if let Err(e) = Shop::sell_event_rod(id, &event, &self.rod_data) {
+                                                say!("Failed to buy rod: {:?}", e);
+                                            }
+                                        }
+                                    }
+                                }
+
                                 ctx.request_repaint();
                             });
                         });
@@ -239,7 +677,7 @@ impl eframe::App for MiniFisher {
             }
 
             // show/hide the bestiary
-            if self.show_bestiary {
+            if self.panel == Some(Panel::Bestiary) {
                 let userfile = read_userfile();
 
                 ui.vertical(|ui| {
@@ -247,6 +685,10 @@ impl eframe::App for MiniFisher {
                     self.generate_navigation_buttons(ui, theme_btn_text);
                     ui.heading("Total Fish Seen:");
                     ui.label(format!("{}/{}", userfile.has_seen.len(), self.fish_data.fish.len()));
+                    ui.heading("Items Found:");
+                    ui.label(format!("{}/{}", userfile.items_seen.len(), self.loot_data.items.len()));
+                    ui.heading("Collections Complete:");
+                    ui.label(format!("{}/{}", userfile.collections.len(), self.collection_data.sets.len()));
                 });
 
                 SidePanel::right("bestiary")
@@ -258,13 +700,77 @@ impl eframe::App for MiniFisher {
                                 ui.set_min_size(Vec2::new(325.0, 0.0));
                                 ui.spacing_mut().item_spacing.y = 10.0;
                                 ui.heading("Bestiary");
-                                ui.label("See information about fish you have caught!");
+                                ui.label("See information about fish and items you have caught!");
+                                ui.horizontal(|ui| {
+                                    ui.selectable_value(&mut self.bestiary_items, false, "Fish");
+                                    ui.selectable_value(&mut self.bestiary_items, true, "Items");
+                                });
+
+                                if self.bestiary_items {
+                                    for seen in &userfile.items_seen {
+                                        let Some(item) = self.loot_data.get_item(seen) else {
+                                            continue;
+                                        };
+                                        let owned = userfile.loot.get(seen).copied().unwrap_or(0);
+
+                                        egui::Frame::group(ui.style()).show(ui, |ui| {
+                                            ui.vertical(|ui| {
+                                                ui.heading(item.to_string());
+                                                ui.label(format!("{}\nOwned: {}\nRecycle value: {}", item.description, owned, item.recycle_value));
+                                                ui.horizontal(|ui| {
+                                                    if ui.add_enabled(owned > 0, egui::Button::new("Recycle")).clicked() {
+                                                        let mut userfile = read_userfile();
+                                                        match recycle(&mut userfile, seen, &self.loot_data) {
+                                                            Ok(value) => {
+                                                                yay!("You recycled a {} for {}!", item, value);
+                                                                update_userfile(userfile);
+                                                            }
+                                                            Err(e) => say!("{}", e),
+                                                        }
+                                                    }
+                                                    if matches!(item.kind, LootKind::Treasure(_)) && ui.add_enabled(owned > 0, egui::Button::new("Open"))
+                                                        .on_hover_text("Modifiers that don't fit your rod wait in the shop for you to choose").clicked() {
+                                                        let mut userfile = read_userfile();
+                                                        match open_treasure(&mut userfile, seen, &self.loot_data, &self.rod_data) {
+                                                            Ok(reward) => {
+                                                                yay!("You opened the {}: {}!", item, reward);
+                                                                update_userfile(userfile);
+                                                            }
+                                                            Err(e) => say!("{}", e),
+                                                        }
+                                                    }
+                                                });
+                                            });
+                                        });
+                                    }
+
+                                    ctx.request_repaint();
+                                    return;
+                                }
+
+                                ui.heading("Collections");
+                                for set in self.collection_data.sets.iter().filter(|s| s.rarity_species().is_none()) {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            if userfile.collections.contains_key(&set.id) {
+                                                ui.heading(format!("📚 {}", set.name));
+                                            } else {
+                                                ui.heading(set.name.clone());
+                                            }
+                                            ui.label(format!("{}\nReward: {}", set.description, set.reward.describe(&self.rod_data)));
+                                            let (collected, total) = set.progress(&userfile);
+                                            ui.add(egui::ProgressBar::new(collected as f32 / total as f32)
+                                                .text(format!("{}/{}", collected, total)));
+                                        });
+                                    });
+                                }
 
+                                ui.heading("Species");
                                 for x in 0..userfile.has_seen.len() {
                                     let seen = &userfile.has_seen[x];
 
                                     egui::Frame::group(ui.style()).show(ui, |ui| {
-                                        let Some(fish) = self.fish_data.get_fish_by_name(seen) else {
+                                        let Some(fish) = self.fish_data.get_fish(seen) else {
                                             ui.vertical(|ui| {
                                                 ui.heading("???");
                                                 ui.label(format!("Unknown Fish: {}", seen));
@@ -272,12 +778,33 @@ impl eframe::App for MiniFisher {
                                             return;
                                         };
                                         ui.vertical(|ui| {
-                                            ui.heading(fish.name.clone());
+                                            ui.heading(fish.to_string());
                                             ui.label(format!("Weight: {} to {}lbs, averaging around {}lbs\
                                             \ndepth: {}ft\
                                             \nMinimum rarity to appear: {}\
-                                            \nValue at average weight: ${}",
-                                            fish.min_weight, fish.max_weight, fish.avg_weight, fish.depth, fish.min_rarity, fish.value));
+                                            \nValue at average weight: {}",
+                                            fish.min_weight, fish.max_weight, fish.avg_weight, fish.depth, fish.min_rarity, Money::new(u64::from(fish.value))));
+
+                                            if let Some(record) = userfile.records.get(seen) {
+                                                ui.separator();
+                                                ui.label(format!("Caught: {} | Lost: {}\
+                                                \nHeaviest: {}lbs | Lightest: {}lbs\
+                                                \nHighest rarity: {}\
+                                                \nMost valuable catch: {}\
+                                                \nFirst caught: {}",
+                                                record.caught, record.lost, record.heaviest, record.lightest,
+                                                record.highest_rarity.as_ref().map_or("None".to_string(), |r| r.to_string()),
+                                                record.best_value,
+                                                record.first_caught.map_or("Never".to_string(), format_timestamp)));
+                                            }
+
+                                            if let Some(set) = self.collection_data.sets.iter().find(|s| s.rarity_species() == Some(seen.as_str())) {
+                                                ui.separator();
+                                                let (collected, total) = set.progress(&userfile);
+                                                ui.label(format!("{}: catch one at every rarity\nReward: {}", set.name, set.reward.describe(&self.rod_data)));
+                                                ui.add(egui::ProgressBar::new(collected as f32 / total as f32)
+                                                    .text(format!("{}/{} rarities", collected, total)));
+                                            }
                                         });
                                     });
                                 }
@@ -290,79 +817,1095 @@ impl eframe::App for MiniFisher {
                 return;
             }
 
-            // not showing a side panel
-            self.generate_navigation_buttons(ui, theme_btn_text);
+            // show/hide the achievements
+            if self.panel == Some(Panel::Achievements) {
+                let userfile = read_userfile();
 
-            ui.vertical_centered(|ui| {
-                ui.heading(self.title.clone());
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading("Achievements Unlocked:");
+                    ui.label(format!("{}/{}", userfile.achievements.len(), self.achievement_data.achievements.len()));
+                });
 
-                ui.spacing_mut().item_spacing.y = 20.0;
+                SidePanel::right("achievements")
+                    .resizable(false)
+                    .show(ctx, |ui| {
 
-                // display the image
-                let img = if catch_data.was_turtle {
-                    egui::Image::new(egui::include_image!("../assets/turtle.png"))
-                        .max_size(Vec2::new(64.0, 64.0))
-                } else {
-                    if self.dark_theme {
-                        if catch_data.caught {
-                            egui::Image::new(egui::include_image!("../assets/rod_with_fish.png"))
-                                .max_size(Vec2::new(64.0, 64.0))
-                        } else {
-                            egui::Image::new(egui::include_image!("../assets/rod.png"))
-                                .max_size(Vec2::new(64.0, 64.0))
-                        }
-                    } else {
-                        if catch_data.caught {
-                            egui::Image::new(egui::include_image!("../assets/rod_with_fish_darker.png"))
-                                .max_size(Vec2::new(64.0, 64.0))
-                        } else {
-                            egui::Image::new(egui::include_image!("../assets/rod_darker.png"))
-                                .max_size(Vec2::new(64.0, 64.0))
-                        }
-                    }
-                };
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Achievements");
+                                ui.label("Reach milestones to earn rewards!");
 
-                ui.add(img);
+                                for achievement in &self.achievement_data.achievements {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            if let Some(unlocked_at) = userfile.achievements.get(&achievement.id) {
+                                                ui.heading(format!("🏆 {}", achievement.name));
+                                                ui.label(format!("{}\nReward: {}\nUnlocked: {}",
+                                                    achievement.description, achievement.reward, format_timestamp(*unlocked_at)));
+                                            } else {
+                                                ui.heading(format!("🔒 {}", achievement.name));
+                                                ui.label(format!("{}\nReward: {}", achievement.description, achievement.reward));
+                                            }
+                                        });
+                                    });
+                                }
 
-                // display the text
-                ui.label(catch_data.display_text.clone());
+                                ctx.request_repaint();
+                            });
+                        });
 
-                // button
-                let fish_button = egui::Button::new(catch_data.cast_btn_txt.clone());
-                let fish_button_ui = ui.add_enabled(!catch_data.cast, fish_button);
-                if fish_button_ui.clicked() {
-                    self.cast_rod();
-                    self.catch_data_ref.lock().unwrap().ctx = Some(ctx.clone());
+                    });
+                return;
+            }
+
+            // show/hide the quest board
+            if self.panel == Some(Panel::Quests) {
+                let mut userfile = read_userfile();
+                let rod = self.rod(&userfile);
+                if userfile.quests.refresh(&self.fish_data, &self.rod_data, &rod, Local::now()) {
+                    update_userfile(userfile.clone());
                 }
 
-                let rod = read_userfile().get_rod(&self.rod_data);
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading("Daily quests reset in:");
+                    ui.label(userfile.quests.get_time_until_reset(QuestKind::Daily));
+                    ui.heading("Weekly quests reset in:");
+                    ui.label(userfile.quests.get_time_until_reset(QuestKind::Weekly));
+                });
 
-                add_hover_txt_mod(fish_button_ui, &rod);
+                SidePanel::right("quests")
+                    .resizable(false)
+                    .show(ctx, |ui| {
 
-                let userfile = read_userfile();
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Quest Board");
+                                ui.label("Complete quests to earn rewards!");
 
-                ui.label(format!("Balance: ${}", userfile.money));
-                ui.label(format!("Fish Caught: {}", userfile.fish_caught));
-                ui.label(format!("Unique Fish: {}/{}", userfile.has_seen.len(), self.fish_data.fish.len()));
-            });
-        });
-    }
-}
+                                for quest in &userfile.quests.quests {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            let kind = match quest.kind {
+                                                QuestKind::Daily => "Daily",
+                                                QuestKind::Weekly => "Weekly",
+                                            };
+                                            if quest.completed {
+                                                ui.heading(format!("✔ {} Quest", kind));
+                                            } else {
+                                                ui.heading(format!("{} Quest", kind));
+                                            }
+                                            ui.label(format!("{}\nReward: {}", quest.objective.describe(&self.fish_data), quest.reward.describe(&self.rod_data)));
+                                            ui.add(egui::ProgressBar::new(quest.progress as f32 / quest.objective.goal() as f32)
+                                                .text(format!("{}/{}", quest.progress, quest.objective.goal())));
+                                        });
+                                    });
+                                }
 
-fn add_hover_txt(response: Response, rod: &BaseRod) {
-    response.on_hover_text(format!("{}\nAverage Catch Rate: {}s\n\
-                                        Catch Chance: {}%\nDepth: {}ft\nWeight: {}lbs",
-                                   rod.name, rod.catch_rate,
-                                   (rod.catch_chance * 100.0) as u32, rod.depth,
-                                   rod.weight_limit));
-}
+                                ctx.request_repaint();
+                            });
+                        });
 
-fn add_hover_txt_mod(response: Response, rod: &Rod) {
-    response.on_hover_text(format!("{}\nAverage Catch Rate: {}s\n\
-                                        Catch Chance: {}%\nDepth: {}ft\nWeight: {}lbs",
-                                   rod, rod.get_catch_rate(),
-                                   rod.get_catch_chance() / 10, rod.get_depth(),
-                                   rod.get_weight_limit()));
+                    });
+                return;
+            }
+
+            // show/hide the catch history
+            if self.panel == Some(Panel::History) {
+                self.refresh_history();
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading("Filters:");
+
+                    let species = self.history_filter.species.as_ref()
+                        .map_or("Any species".to_string(), |id| self.fish_data.species_name(id));
+                    egui::ComboBox::from_id_source("history_species")
+                        .selected_text(species)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.history_filter.species, None, "Any species");
+                            for fish in self.fish_data.fish.iter() {
+                                ui.selectable_value(&mut self.history_filter.species, Some(fish.id.clone()), fish.to_string());
+                            }
+                        });
+
+                    let rarity = self.history_filter.rarity.as_ref().map_or("Any rarity".to_string(), |r| r.to_string());
+                    egui::ComboBox::from_id_source("history_rarity")
+                        .selected_text(rarity)
+                        .show_ui(ui, |ui| {
+                            if ui.selectable_label(self.history_filter.rarity.is_none(), "Any rarity").clicked() {
+                                self.history_filter.rarity = None;
+                            }
+                            for rarity in FishRarity::all() {
+                                let selected = self.history_filter.rarity.as_ref().is_some_and(|r| r.ident() == rarity.ident());
+                                if ui.selectable_label(selected, rarity.to_string()).clicked() {
+                                    self.history_filter.rarity = Some(rarity);
+                                }
+                            }
+                        });
+
+                    let outcome = self.history_filter.outcome.map_or("Any outcome".to_string(), |o| o.to_string());
+                    egui::ComboBox::from_id_source("history_outcome")
+                        .selected_text(outcome)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.history_filter.outcome, None, "Any outcome");
+                            for outcome in CatchOutcome::all() {
+                                ui.selectable_value(&mut self.history_filter.outcome, Some(outcome), outcome.to_string());
+                            }
+                        });
+
+                    egui::ComboBox::from_id_source("history_range")
+                        .selected_text(self.history_filter.range.to_string())
+                        .show_ui(ui, |ui| {
+                            for range in HistoryRange::all() {
+                                ui.selectable_value(&mut self.history_filter.range, range, range.to_string());
+                            }
+                            let picking_dates = matches!(self.history_filter.range, HistoryRange::Dates { .. });
+                            if ui.selectable_label(picking_dates, "Between dates").clicked() && !picking_dates {
+                                let today = Local::now().date_naive();
+                                self.history_filter.range = HistoryRange::Dates { from: today - chrono::Duration::days(7), to: today };
+                            }
+                        });
+                    if let HistoryRange::Dates { from, to } = &mut self.history_filter.range {
+                        ui.horizontal(|ui| {
+                            ui.label("From");
+                            ui.add(egui_extras::DatePickerButton::new(from).id_source("history_from"));
+                            ui.label("to");
+                            ui.add(egui_extras::DatePickerButton::new(to).id_source("history_to"));
+                        });
+                    }
+
+                    ui.horizontal(|ui| {
+                        let filtered = self.history_filter.apply(&self.history);
+                        for (label, format) in [("Export CSV", ExportFormat::Csv), ("Export JSON", ExportFormat::Json)] {
+                            if ui.button(label).on_hover_text("Export the filtered history to ./data/exports").clicked() {
+                                self.history_message = match export_history(&filtered, format) {
+                                    Ok(path) => format!("Exported to {}", path),
+                                    Err(e) => format!("Failed to export: {}", e),
+                                };
+                            }
+                        }
+                    });
+                    ui.label(self.history_message.clone());
+                });
+
+                SidePanel::right("history")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Catch History");
+
+                                let filtered = self.history_filter.apply(&self.history);
+                                ui.label(format!("Showing the latest {} of {} casts",
+                                    filtered.len().min(MAX_HISTORY_SHOWN), filtered.len()));
+
+                                for record in filtered.iter().rev().take(MAX_HISTORY_SHOWN) {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.label(format!("{} - {}\
+                                            \n{} {} at {}lbs ({})\
+                                            \nRod: {}",
+                                            format_timestamp(record.timestamp), record.outcome,
+                                            record.rarity, self.fish_data.species_name(&record.species), record.weight, record.value,
+                                            self.rod_data.rod_name(&record.rod)));
+                                        });
+                                    });
+                                }
+
+                                ctx.request_repaint();
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the statistics
+            if self.panel == Some(Panel::Stats) {
+                self.refresh_history();
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading("Total Casts:");
+                    ui.label(self.stats.total_casts.to_string());
+                    ui.heading("Total Income:");
+                    ui.label(format!("{}", self.stats.total_income));
+                    ui.heading("Average Cast:");
+                    ui.label(format!("{:.1}s", self.stats.average_cast_duration));
+                });
+
+                SidePanel::right("stats")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Statistics");
+                            });
+
+                            ui.spacing_mut().item_spacing.y = 10.0;
+
+                            ui.label("Income per day");
+                            let income: Vec<f32> = self.stats.income_per_day.iter().map(|(_, v)| v.as_f32()).collect();
+                            let first_day = self.stats.income_per_day.first().map_or(String::new(), |(d, _)| d.format("%m/%d").to_string());
+                            let last_day = self.stats.income_per_day.last().map_or(String::new(), |(d, _)| d.format("%m/%d").to_string());
+                            charts::line_chart(ui, &income, &first_day, &last_day);
+
+                            ui.label("Catches per rarity (caught / expected)")
+                                .on_hover_text("Blue: your catches, orange: the theoretical rarity distribution");
+                            let rarities: Vec<(String, f32, f32)> = self.stats.rarity_distribution.iter()
+                                .map(|(r, observed, expected)| (r.to_string(), *observed * 100.0, *expected * 100.0))
+                                .collect();
+                            charts::comparison_chart(ui, &rarities, |v| format!("{:.0}%", v));
+
+                            ui.label("Catch success rate per rod");
+                            let rods: Vec<(String, f32)> = self.stats.success_per_rod.iter()
+                                .map(|(rod, caught, total)| (truncate(&self.rod_data.rod_name(rod), 16), *caught as f32 / *total as f32 * 100.0))
+                                .collect();
+                            charts::bar_chart(ui, &rods, |v| format!("{:.0}%", v));
+
+                            ui.label("Line breaks per species");
+                            let breaks: Vec<(String, f32)> = self.stats.line_breaks_per_species.iter()
+                                .map(|(species, count)| (truncate(&self.fish_data.species_name(species), 16), *count as f32))
+                                .collect();
+                            if breaks.is_empty() {
+                                ui.label("No broken lines yet!");
+                            }
+                            charts::bar_chart(ui, &breaks, |v| format!("{}", v));
+
+                            ctx.request_repaint();
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the skill tree
+            if self.panel == Some(Panel::Skills) {
+                let mut userfile = read_userfile();
+                let (level, xp_into_level) = level_for_xp(userfile.xp);
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading(format!("Angler Level {}", level));
+                    ui.add(egui::ProgressBar::new(xp_into_level as f32 / xp_to_next_level(level) as f32)
+                        .text(format!("{}/{} xp", xp_into_level, xp_to_next_level(level))));
+                    ui.heading("Skill Points:");
+                    ui.label(userfile.unspent_skill_points().to_string());
+                });
+
+                SidePanel::right("skills")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Skill Tree");
+                                ui.label("Earn xp by catching fish and spend skill points on perks!");
+
+                                for skill in &self.skill_data.skills {
+                                    let rank = userfile.skills.get(&skill.id).copied().unwrap_or(0);
+
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.heading(format!("{} ({}/{})", skill.name, rank, skill.max_rank));
+                                            ui.label(skill.description.clone());
+                                            if let Some(required) = skill.requires.as_ref().and_then(|r| self.skill_data.get_skill_by_id(r)) {
+                                                ui.label(format!("Requires: {}", required.name));
+                                            }
+                                        });
+                                        let learn_button = egui::Button::new("Learn");
+                                        let unlocked = skill.requires.as_ref().is_none_or(|r| userfile.skills.get(r).copied().unwrap_or(0) > 0);
+                                        let can_learn = unlocked && rank < skill.max_rank && userfile.unspent_skill_points() > 0;
+                                        if ui.add_enabled(can_learn, learn_button).clicked() {
+                                            match userfile.learn_skill(&self.skill_data, &skill.id) {
+                                                Ok(()) => update_userfile(userfile.clone()),
+                                                Err(e) => say!("Failed to learn skill: {}", e),
+                                            }
+                                        }
+                                    });
+                                }
+
+                                ctx.request_repaint();
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the workshop
+            if self.panel == Some(Panel::Workshop) {
+                let mut userfile = read_userfile();
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading("Components:");
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        if userfile.components.is_empty() {
+                            ui.label("None yet! Buy them in the shop or find them while fishing.");
+                        }
+                        for component in &self.component_data.components {
+                            if let Some(count) = userfile.components.get(&component.name) {
+                                let label = ui.label(format!("{} x{}", component.name, count));
+                                add_hover_txt_component(label, component);
+                            }
+                        }
+                    });
+                });
+
+                SidePanel::right("workshop")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Workshop");
+                                ui.label("Combine a blank, reel, line and hook into your own rod!\nCrafting uses up the components.");
+
+                                for (i, kind) in ComponentKind::all().into_iter().enumerate() {
+                                    let selected = self.craft_selection[i].clone().unwrap_or(format!("Choose a {}", kind));
+                                    egui::ComboBox::from_id_source(format!("craft_{}", kind))
+                                        .selected_text(selected)
+                                        .width(250.0)
+                                        .show_ui(ui, |ui| {
+                                            for component in self.component_data.components.iter().filter(|c| c.kind == kind) {
+                                                if userfile.components.contains_key(&component.name) {
+                                                    ui.selectable_value(&mut self.craft_selection[i], Some(component.name.clone()), component.name.clone());
+                                                }
+                                            }
+                                        });
+                                }
+
+                                let crafted = match &self.craft_selection {
+                                    [Some(blank), Some(reel), Some(line), Some(hook)] => Some(CraftedRod {
+                                        blank: blank.clone(),
+                                        reel: reel.clone(),
+                                        line: line.clone(),
+                                        hook: hook.clone(),
+                                    }),
+                                    _ => None,
+                                };
+
+                                if let Some(base) = crafted.as_ref().and_then(|c| c.to_base(&self.component_data)) {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.heading(base.name.clone());
+                                            let desc = ui.label(format!("{}\n(Hover for more information)", base.description));
+                                            add_hover_txt(desc, &base);
+                                        });
+                                    });
+                                }
+
+                                // crafting replaces the equipped rod and clears its modifiers
+                                let current = self.rod(&userfile);
+                                if crafted.is_some() {
+                                    let warning = if current.modifiers.is_empty() {
+                                        format!("This replaces your {}!", current)
+                                    } else {
+                                        format!("This replaces your {} and its modifiers ({})!", current,
+                                                current.modifiers.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(", "))
+                                    };
+                                    ui.colored_label(Color32::YELLOW, warning);
+                                }
+
+                                let craft_text = if self.confirm_craft { "Are you sure?" } else { "Craft" };
+                                if ui.add_enabled(crafted.is_some(), egui::Button::new(craft_text)).clicked() {
+                                    if self.confirm_craft {
+                                        match craft_rod(&mut userfile, crafted.unwrap(), &self.component_data) {
+                                            Ok(base) => {
+                                                yay!("You crafted a {}!", base.name);
+                                                update_userfile(userfile.clone());
+                                                self.craft_selection = Default::default();
+                                            }
+                                            Err(e) => say!("Failed to craft rod: {}", e),
+                                        }
+                                        self.confirm_craft = false;
+                                    } else {
+                                        self.confirm_craft = true;
+                                    }
+                                }
+
+                                ctx.request_repaint();
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the aquarium
+            if self.panel == Some(Panel::Aquarium) {
+                let mut userfile = read_userfile();
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    ui.heading("Tank Slots:");
+                    ui.label(format!("{}/{}", userfile.aquarium.fish.len(), userfile.aquarium.slots));
+                    ui.heading("Income:");
+                    ui.label(format!("${:.1} an hour", userfile.aquarium.hourly_income()))
+                        .on_hover_text("Rare and better fish on display earn a little money every hour");
+
+                    match userfile.aquarium.slot_cost() {
+                        Some(cost) => {
+                            let upgrade_button = egui::Button::new(format!("Add a slot ({})", cost));
+                            if ui.add_enabled(userfile.money >= cost, upgrade_button).clicked() {
+                                match upgrade_tank(&mut userfile) {
+                                    Ok(slots) => {
+                                        yay!("Your aquarium now holds {} fish!", slots);
+                                        update_userfile(userfile.clone());
+                                    }
+                                    Err(e) => say!("Failed to upgrade aquarium: {}", e),
+                                }
+                            }
+                        }
+                        None => {
+                            ui.label(format!("Fully upgraded! ({} slots)", MAX_SLOTS));
+                        }
+                    }
+                });
+
+                SidePanel::right("aquarium")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Aquarium");
+                                ui.label("Keep your best catches from the main screen instead of selling them!");
+
+                                let max_weight = userfile.aquarium.fish.iter().map(|f| f.weight).fold(0.0, f32::max).max(f32::EPSILON);
+                                let tank: Vec<(f32, Color32)> = userfile.aquarium.fish.iter()
+                                    .map(|f| (f.weight / max_weight, rarity_color(&f.rarity)))
+                                    .collect();
+                                charts::tank(ui, &tank, ctx.input(|i| i.time));
+
+                                if userfile.aquarium.fish.is_empty() {
+                                    ui.label("Your tank is empty!");
+                                }
+                                let mut sold = None;
+                                for (i, fish) in userfile.aquarium.fish.iter().enumerate() {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.colored_label(rarity_color(&fish.rarity), fish.describe(&self.fish_data));
+                                            ui.label(format!("{}lbs | worth {}\nKept on {}", fish.weight, fish.value, format_timestamp(fish.kept_at)));
+                                            if fish.hourly_income() > 0.0 {
+                                                ui.label(format!("Earning ${:.1} an hour", fish.hourly_income()));
+                                            }
+                                        });
+                                        if ui.button("Sell").on_hover_text(format!("Sell for {}", fish.value)).clicked() {
+                                            sold = Some(i);
+                                        }
+                                    });
+                                }
+                                if let Some(i) = sold {
+                                    match sell_kept_fish(&mut userfile, i) {
+                                        Ok(fish) => {
+                                            yay!("You sold your {} for {}!", fish.describe(&self.fish_data), fish.value);
+                                            update_userfile(userfile.clone());
+                                        }
+                                        Err(e) => say!("Failed to sell fish: {}", e),
+                                    }
+                                }
+
+                                ctx.request_repaint();
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the tournaments
+            if self.panel == Some(Panel::Tournaments) {
+                let mut userfile = read_userfile();
+                let running = userfile.tournament.as_ref()
+                    .and_then(|active| self.tournament_data.get_tournament(&active.id).map(|t| (active.clone(), t.clone())));
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+                    match &running {
+                        Some((active, tournament)) => {
+                            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                            add_leaderboard(ui, active, tournament, &self.boat_data);
+                        }
+                        None => {
+                            ui.heading("No tournament running");
+                            ui.label("Enter one from the list to compete against other anglers!");
+                        }
+                    }
+                });
+
+                SidePanel::right("tournaments")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+                                ui.heading("Tournaments");
+                                ui.label("Pay the entry fee, then out-fish the competition before time runs out!");
+
+                                for tournament in &self.tournament_data.tournaments {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.heading(tournament.name.clone());
+                                            ui.label(format!("{}\n{} | {} minutes at {}\nEntry fee: {}\nPrizes: {}",
+                                                tournament.description, tournament.kind, tournament.minutes, self.boat_data.location_name(&tournament.location),
+                                                tournament.entry_fee,
+                                                tournament.prizes.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" / ")));
+                                        });
+                                        let enter_button = egui::Button::new("Enter");
+                                        let reachable = userfile.can_travel_to(&tournament.location, &self.boat_data);
+                                        let can_enter = running.is_none() && userfile.money >= tournament.entry_fee && reachable.is_ok();
+                                        let mut enter_button_ui = ui.add_enabled(can_enter, enter_button);
+                                        if let Err(e) = &reachable {
+                                            enter_button_ui = enter_button_ui.on_disabled_hover_text(e.to_string());
+                                        }
+                                        if enter_button_ui.clicked() {
+                                            match enter_tournament(&mut userfile, tournament, &self.boat_data) {
+                                                Ok(()) => {
+                                                    yay!("The {} has begun! Fish at {} to score.", tournament.name, self.boat_data.location_name(&tournament.location));
+                                                    self.catch_data_ref.lock().unwrap().tournament_ends_at =
+                                                        userfile.tournament.as_ref().map(|active| active.ends_at);
+                                                    update_userfile(userfile.clone());
+                                                }
+                                                Err(e) => say!("Failed to enter tournament: {}", e),
+                                            }
+                                        }
+                                    });
+                                }
+
+                                ui.heading("Results");
+                                if userfile.tournament_history.is_empty() {
+                                    ui.label("You haven't finished a tournament yet!");
+                                }
+                                for result in userfile.tournament_history.iter().rev() {
+                                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.label(format!("{}\nPrize: {}\nFinished: {}",
+                                                result, result.prize, format_timestamp(result.finished_at)));
+                                        });
+                                    });
+                                }
+
+                                ctx.request_repaint();
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the LAN derby
+            if self.panel == Some(Panel::Derby) {
+                let view = catch_data.derby.as_ref().map(|derby| derby.view());
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+
+                    match &catch_data.derby {
+                        Some(derby) => {
+                            ui.heading("Fishing in a derby");
+                            ui.label(format!("Connected to {} as {}", derby.host, derby.name));
+                            if let Some(reason) = view.as_ref().and_then(|v| v.disconnected.clone()) {
+                                ui.label(reason);
+                            }
+                            if ui.button("Leave").clicked() {
+                                self.catch_data_ref.lock().unwrap().derby = None;
+                            }
+                            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                        }
+                        None => {
+                            ui.heading("Derby");
+                            ui.label("Fish with other players on your network and share every catch!");
+                            ui.horizontal(|ui| {
+                                ui.label("Name:");
+                                ui.text_edit_singleline(&mut self.derby_name);
+                            });
+                            ui.horizontal(|ui| {
+                                ui.label("Address:");
+                                ui.text_edit_singleline(&mut self.derby_address);
+                            });
+
+                            ui.horizontal(|ui| {
+                                let host_button = ui.add_enabled(catch_data.derby_hosting.is_none(), egui::Button::new("Host"))
+                                    .on_hover_text("Host a derby on the address's port, then join it");
+                                if host_button.clicked() {
+                                    let port = self.derby_address.rsplit(':').next()
+                                        .and_then(|p| p.parse().ok())
+                                        .unwrap_or(DEFAULT_PORT);
+                                    match Host::bind(("0.0.0.0", port)) {
+                                        Ok(host) => {
+                                            // port 0 lets the system pick one
+                                            let port = host.local_addr().map_or(port, |addr| addr.port());
+                                            let catch_data_ref = self.catch_data_ref.clone();
+                                            thread::spawn(move || {
+                                                if let Err(e) = host.run() {
+                                                    nay!("The derby host stopped: {}", e);
+                                                    let mut catch_data = catch_data_ref.lock().unwrap();
+                                                    catch_data.derby_hosting = None;
+                                                    if let Some(ctx) = &catch_data.ctx {
+                                                        ctx.request_repaint();
+                                                    }
+                                                }
+                                            });
+                                            self.catch_data_ref.lock().unwrap().derby_hosting = Some(port);
+                                            self.derby_address = format!("127.0.0.1:{}", port);
+                                            yay!("Hosting a derby on port {}!", port);
+                                        }
+                                        Err(e) => nay!("Failed to host a derby: {}", e),
+                                    }
+                                }
+
+                                let join_text = if catch_data.derby_joining { "Joining..." } else { "Join" };
+                                if ui.add_enabled(!catch_data.derby_joining, egui::Button::new(join_text)).clicked() {
+                                    self.catch_data_ref.lock().unwrap().derby_joining = true;
+                                    let catch_data_ref = self.catch_data_ref.clone();
+                                    let address = self.derby_address.clone();
+                                    let name = self.derby_name.clone();
+                                    // connecting can take seconds, so it's kept off the UI thread
+                                    thread::spawn(move || {
+                                        let result = DerbyClient::connect(&address, &name);
+                                        let mut catch_data = catch_data_ref.lock().unwrap();
+                                        catch_data.derby_joining = false;
+                                        match result {
+                                            Ok(derby) => {
+                                                yay!("Joined the derby at {}!", address);
+                                                catch_data.derby = Some(Arc::new(derby));
+                                            }
+                                            Err(e) => say!("Failed to join the derby: {}", e),
+                                        }
+                                    });
+                                }
+                            });
+                            if catch_data.derby_joining {
+                                ui.label(format!("Joining the derby at {}...", self.derby_address));
+                                ctx.request_repaint_after(std::time::Duration::from_millis(250));
+                            }
+                            if let Some(port) = catch_data.derby_hosting {
+                                ui.label(format!("Hosting on port {}, other players join with your LAN address", port));
+                            }
+                        }
+                    }
+                });
+
+                SidePanel::right("derby")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+
+                                let Some(view) = &view else {
+                                    ui.heading("Not in a derby");
+                                    ui.label("Host or join one to see the leaderboard and live feed!");
+                                    return;
+                                };
+
+                                ui.heading("Leaderboard");
+                                for (i, standing) in view.leaderboard.iter().enumerate() {
+                                    let status = if standing.online { "" } else { " (offline)" };
+                                    ui.label(format!("{}. {}{} - {} from {} fish, heaviest {}lbs", i + 1, standing.name, status,
+                                                     Money::new(standing.value), standing.catches, standing.heaviest));
+                                }
+
+                                ui.heading("Live Feed");
+                                if view.feed.is_empty() {
+                                    ui.label("Nothing has happened yet!");
+                                }
+                                for entry in view.feed.iter().rev() {
+                                    ui.label(format!("[{}] {}", format_timestamp(entry.timestamp), entry));
+                                }
+
+                                ctx.request_repaint();
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // show/hide the online leaderboard
+            if self.panel == Some(Panel::Online) {
+                let status = self.uploader.status();
+
+                ui.vertical(|ui| {
+                    ui.spacing_mut().item_spacing.y = 10.0;
+                    self.generate_navigation_buttons(ui, theme_btn_text);
+
+                    ui.heading("Online Leaderboard");
+                    ui.label("Submit your biggest fish, total earnings and bestiary progress to compete with everyone!");
+                    ui.checkbox(&mut self.online_config.enabled, "Submit my scores");
+                    ui.horizontal(|ui| {
+                        ui.label("Name:");
+                        ui.text_edit_singleline(&mut self.online_config.name);
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Server:");
+                        ui.text_edit_singleline(&mut self.online_config.endpoint);
+                    });
+
+                    let changed = self.online_config != self.saved_online_config;
+                    let apply_text = if changed { "Save & Submit" } else { "Submit Now" };
+                    if ui.add_enabled(self.online_config.enabled || changed, egui::Button::new(apply_text)).clicked() {
+                        if changed {
+                            update_config(&self.online_config);
+                            self.saved_online_config = self.online_config.clone();
+                        }
+                        self.uploader.sync_now();
+                        say!("Submitting your scores to {}", self.online_config.endpoint);
+                    }
+
+                    if status.queued > 0 {
+                        ui.label(format!("{} submissions waiting to be sent", status.queued));
+                    }
+                    if let Some(last_sent) = status.last_sent {
+                        ui.label(format!("Last submitted at {}", format_timestamp(last_sent)));
+                    }
+                    if let Some(error) = &status.error {
+                        ui.colored_label(Color32::LIGHT_RED, error);
+                    }
+                });
+
+                SidePanel::right("online")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            ui.vertical_centered(|ui| {
+                                ui.set_min_size(Vec2::new(325.0, 0.0));
+                                ui.spacing_mut().item_spacing.y = 10.0;
+
+                                let Some(leaderboard) = &status.leaderboard else {
+                                    ui.heading("No leaderboard yet");
+                                    ui.label("Turn on score submission to see how you stack up!");
+                                    return;
+                                };
+
+                                ui.heading("Total Value");
+                                for (i, leader) in leaderboard.total_value.iter().enumerate() {
+                                    ui.label(format!("{}. {} - {}", i + 1, leader.player, Money::new(leader.total_value)));
+                                }
+
+                                ui.heading("Bestiary");
+                                for (i, leader) in leaderboard.bestiary.iter().enumerate() {
+                                    ui.label(format!("{}. {} - {}/{}", i + 1, leader.player, leader.species_seen, leader.species_total));
+                                }
+
+                                ui.heading("Biggest Fish");
+                                if leaderboard.biggest_fish.is_empty() {
+                                    ui.label("Nobody has caught anything yet!");
+                                }
+                                for leader in &leaderboard.biggest_fish {
+                                    ui.label(format!("{} - {}lbs by {}", leader.species, leader.weight, leader.player));
+                                }
+
+                                ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                            });
+                        });
+
+                    });
+                return;
+            }
+
+            // not showing a side panel
+            self.generate_navigation_buttons(ui, theme_btn_text);
+
+            // live standings while a tournament is running
+            let running = read_userfile().tournament
+                .and_then(|active| self.tournament_data.get_tournament(&active.id).map(|t| (active, t)));
+            if let Some((active, tournament)) = running {
+                ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                SidePanel::right("leaderboard")
+                    .resizable(false)
+                    .show(ctx, |ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.set_min_size(Vec2::new(200.0, 0.0));
+                            ui.spacing_mut().item_spacing.y = 10.0;
+                            add_leaderboard(ui, &active, tournament, &self.boat_data);
+                        });
+                    });
+            }
+
+            ui.vertical_centered(|ui| {
+                ui.heading(self.title.clone());
+
+                ui.spacing_mut().item_spacing.y = 20.0;
+
+                // running events
+                for event in self.event_data.active() {
+                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                        ui.vertical_centered(|ui| {
+                            ui.heading(format!("🎉 {} 🎉", event.name)).on_hover_text(event.description.clone());
+                            ui.label(format!("Ends in: {}", event.get_time_remaining()));
+                        });
+                    });
+                }
+
+                // display the image
+                let img = if let Some(image) = &catch_data.event_image {
+                    egui::Image::new(image.clone())
+                        .max_size(Vec2::new(64.0, 64.0))
+                } else {
+                    if self.dark_theme {
+                        if catch_data.caught {
+                            egui::Image::new(egui::include_image!("../assets/rod_with_fish.png"))
+                                .max_size(Vec2::new(64.0, 64.0))
+                        } else {
+                            egui::Image::new(egui::include_image!("../assets/rod.png"))
+                                .max_size(Vec2::new(64.0, 64.0))
+                        }
+                    } else {
+                        if catch_data.caught {
+                            egui::Image::new(egui::include_image!("../assets/rod_with_fish_darker.png"))
+                                .max_size(Vec2::new(64.0, 64.0))
+                        } else {
+                            egui::Image::new(egui::include_image!("../assets/rod_darker.png"))
+                                .max_size(Vec2::new(64.0, 64.0))
+                        }
+                    }
+                };
+
+                ui.add(img);
+
+                // display the text
+                ui.label(catch_data.display_text.clone());
+
+                // button
+                let fish_button = egui::Button::new(catch_data.cast_btn_txt.clone());
+                let fish_button_ui = ui.add_enabled(!catch_data.cast, fish_button);
+                if fish_button_ui.clicked() {
+                    self.cast_rod();
+                    self.catch_data_ref.lock().unwrap().ctx = Some(ctx.clone());
+                }
+
+                let rod = self.rod(&read_userfile());
+
+                add_hover_txt_mod(fish_button_ui, &rod);
+
+                if let Some(kept) = &catch_data.last_catch {
+                    let keep_button = ui.button(format!("Keep in aquarium (-{})", kept.value))
+                        .on_hover_text("Take the fish back from the market and put it on display");
+                    if keep_button.clicked() {
+                        let mut userfile = read_userfile();
+                        match keep_fish(&mut userfile, kept.clone()) {
+                            Ok(()) => {
+                                yay!("Your {} is now in your aquarium!", kept.describe(&self.fish_data));
+                                update_userfile(userfile);
+                                self.catch_data_ref.lock().unwrap().last_catch = None;
+                            }
+                            Err(e) => say!("{}", e),
+                        }
+                    }
+                }
+
+                let userfile = read_userfile();
+
+                ui.label(format!("Balance: {} | Angler Level: {}", userfile.money, level_for_xp(userfile.xp).0));
+                ui.label(format!("Fish Caught: {} | Prestige: {}", userfile.fish_caught, userfile.prestige));
+                ui.label(format!("Unique Fish: {}/{}", self.fish_data.regular_seen(&userfile.has_seen), self.fish_data.regular_fish().count()))
+                    .on_hover_text("Event and prestige fish don't count towards the bestiary");
+
+                let location = userfile.get_location(&self.boat_data);
+                let boat_tier = userfile.get_boat(&self.boat_data).map(|b| b.tier).unwrap_or(0);
+                egui::ComboBox::from_label("Location")
+                    .selected_text(location.to_string())
+                    .show_ui(ui, |ui| {
+                        for option in self.boat_data.locations.iter() {
+                            let reachable = option.tier <= boat_tier;
+                            let option_ui = ui.add_enabled(reachable, egui::SelectableLabel::new(option.id == location.id, option.to_string()))
+                                .on_hover_text(option.description.clone())
+                                .on_disabled_hover_text(format!("{}\nNeeds a tier {} boat", option.description, option.tier));
+                            if option_ui.clicked() {
+                                let mut userfile = read_userfile();
+                                match userfile.travel_to(&option.id, &self.boat_data) {
+                                    Ok(()) => {
+                                        say!("You travelled to {}!", option);
+                                        update_userfile(userfile);
+                                    }
+                                    Err(e) => say!("{}", e),
+                                }
+                            }
+                        }
+                    });
+
+                if userfile.auto_fisher {
+                    let mut auto_fish = catch_data.auto_fish;
+                    let auto_fish_checkbox = ui.checkbox(&mut auto_fish, "Auto-fish")
+                        .on_hover_text("Let the auto-fisher cast for you at reduced efficiency");
+                    if auto_fish_checkbox.changed() {
+                        self.set_auto_fish(auto_fish);
+                    }
+                    if auto_fish {
+                        ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                    }
+                }
+            });
+        });
+
+        // show what happened while the game was closed
+        if let Some(summary) = &self.offline_summary {
+            let mut open = true;
+            egui::Window::new("While you were away...")
+                .collapsible(false)
+                .resizable(false)
+                .open(&mut open)
+                .show(ctx, |ui| {
+                    ui.label(format!("Your auto-fisher cast {} times over {} hours and {} minutes:\
+                    \nCaught: {}\
+                    \nGot away: {}\
+                    \nLines broken: {}\
+                    \nStolen: {}\
+                    \nNew species: {}\
+                    \nEarned: {}",
+                    summary.casts, summary.elapsed.num_hours(), summary.elapsed.num_minutes() % 60,
+                    summary.caught, summary.escaped, summary.line_breaks, summary.stolen,
+                    summary.new_species, summary.money));
+                });
+            if !open {
+                self.offline_summary = None;
+            }
+        }
+    }
+}
+
+/// Shows the standings of a running tournament
+fn add_leaderboard(ui: &mut Ui, active: &ActiveTournament, tournament: &Tournament, boat_data: &BoatData) {
+    ui.heading(tournament.name.clone());
+    ui.label(format!("{} at {}\nTime left: {}", tournament.kind, boat_data.location_name(&tournament.location), active.get_time_remaining()));
+
+    for (i, entrant) in active.standings(tournament.kind).into_iter().enumerate() {
+        let text = format!("{}. {} - {}", i + 1, entrant.name, tournament.kind.describe_score(entrant));
+        if entrant.is_player {
+            ui.strong(text);
+        } else {
+            ui.label(text);
+        }
+    }
+}
+
+/// Whether a rod purchase can go ahead, buying over a crafted rod needs a second click
+fn confirm_rod_purchase(confirm_buy_rod: &mut Option<String>, crafted: bool, id: &str) -> bool {
+    if !crafted || confirm_buy_rod.as_deref() == Some(id) {
+        *confirm_buy_rod = None;
+        return true;
+    }
+    *confirm_buy_rod = Some(id.to_string());
+    false
+}
+
+/// Shows a rod for sale, returns true if its buy button was clicked
+fn add_shop_entry(ui: &mut Ui, rod: &BaseRod, price: String, has_money: bool, confirming: bool) -> bool {
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        ui.vertical(|ui| {
+            ui.heading(rod.to_string());
+            let desc = ui.label(format!("{}\n(Hover for more information)", rod.description));
+            add_hover_txt(desc, rod);
+            ui.label(price);
+        });
+        let buy_button = egui::Button::new(if confirming { "Are you sure?" } else { "Buy" });
+        let buy_button_ui = ui.add_enabled(has_money, buy_button);
+        let clicked = buy_button_ui.clicked();
+        add_hover_txt(buy_button_ui, rod);
+        clicked
+    }).inner
+}
+
+fn add_hover_txt_component(response: Response, component: &RodComponent) {
+    response.on_hover_text(format!("{} ({})\nCatch Rate: +{}s\n\
+                                        Catch Chance: +{}%\nDepth: +{}ft\nWeight: +{}lbs",
+                                   component.name, component.kind, component.catch_rate,
+                                   (component.catch_chance * 100.0) as u32, component.depth,
+                                   component.weight_limit));
+}
+
+fn add_hover_txt(response: Response, rod: &BaseRod) {
+    response.on_hover_text(format!("{}\nAverage Catch Rate: {}s\n\
+                                        Catch Chance: {}%\nDepth: {}ft\nWeight: {}lbs",
+                                   rod, rod.catch_rate,
+                                   (rod.catch_chance * 100.0) as u32, rod.depth,
+                                   rod.weight_limit));
+}
+
+fn add_hover_txt_mod(response: Response, rod: &Rod) {
+    let modifier_tier: String = rod.modifiers.iter()
+        .map(|m| format!("\nModifier: {} ({})", m, m.tier))
+        .collect();
+    let tackle: String = rod.tackle.iter()
+        .map(|t| format!("\n{}: {} ({})", t.tackle.slot, t, t.tackle.describe(t.level)))
+        .collect();
+    response.on_hover_text(format!("{}{}{}\nAverage Catch Rate: {}s\n\
+                                        Catch Chance: {}%\nDepth: {}ft\nWeight: {}lbs",
+                                   rod, modifier_tier, tackle, rod.get_catch_rate(),
+                                   rod.get_catch_chance() / 10, rod.get_depth(),
+                                   rod.get_weight_limit()));
+}
+
+/// Like `add_hover_txt_mod`, but explains where each stat came from
+fn add_hover_txt_breakdown(response: Response, rod: &Rod) {
+    let modifier_tier: String = rod.modifiers.iter()
+        .map(|m| format!("\nModifier: {} ({})", m, m.tier))
+        .collect();
+    response.on_hover_text(format!("{}{}\n\n{}", rod, modifier_tier, rod.breakdown()));
+}
+
+fn rarity_color(rarity: &FishRarity) -> Color32 {
+    match rarity {
+        FishRarity::Common => Color32::from_rgb(180, 180, 180),
+        FishRarity::Uncommon => Color32::from_rgb(90, 200, 90),
+        FishRarity::Rare => Color32::from_rgb(70, 140, 220),
+        FishRarity::Elusive => Color32::from_rgb(170, 90, 220),
+        FishRarity::Legendary => Color32::from_rgb(240, 170, 40),
+        FishRarity::Mythical => Color32::from_rgb(230, 60, 60),
+    }
+}
+
+/// Shortens text to fit in chart labels
+fn truncate(text: &str, max_len: usize) -> String {
+    if text.chars().count() > max_len {
+        let mut text: String = text.chars().take(max_len - 3).collect();
+        text.push_str("...");
+        text
+    } else {
+        text.to_string()
+    }
+}
+
+/// Formats a saved unix timestamp in local time
+fn format_timestamp(timestamp: i64) -> String {
+    NaiveDateTime::from_timestamp_opt(timestamp, 0)
+        .map(|t| t.and_utc().with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
 }
 
 fn load_icon() -> IconData {
@@ -0,0 +1,495 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use chrono::Local;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::data::fish::FishData;
+use crate::data::history::{CatchOutcome, CatchRecord, read_history};
+use crate::data::money::Money;
+use crate::data::userfile::{try_read_userfile, UserValues};
+use crate::nay;
+use super::protocol::{BiggestFish, DEFAULT_PORT, DEV_SECRET, ErrorResponse, Leaderboard, Scores, ScoreSubmission, sign, SIGNATURE_HEADER};
+
+/// how often scores are submitted while everything is working
+const SUBMIT_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// the wait after the first failure, doubled every time it fails again
+const RETRY_MIN: Duration = Duration::from_secs(15);
+const RETRY_MAX: Duration = SUBMIT_INTERVAL;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// the oldest submissions are dropped past this, the newer ones carry the same scores or better
+const MAX_QUEUED: usize = 20;
+
+/// Where and as whom scores are submitted, nothing is sent until `enabled` is set
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OnlineConfig {
+    pub enabled: bool,
+    /// base url of the leaderboard server
+    pub endpoint: String,
+    pub name: String,
+    /// random id the server tells players apart by
+    pub player_id: String,
+    /// the key submissions are signed with, has to match the server's
+    pub secret: String,
+}
+
+impl Default for OnlineConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: format!("http://127.0.0.1:{}", DEFAULT_PORT),
+            name: "Angler".to_string(),
+            player_id: format!("{:016x}", rand::thread_rng().gen::<u64>()),
+            secret: DEV_SECRET.to_string(),
+        }
+    }
+}
+
+pub fn get_config_path() -> String {
+    "./data/online.json".to_string()
+}
+
+pub fn read_config() -> OnlineConfig {
+    let Ok(contents) = std::fs::read_to_string(get_config_path()) else {
+        let config = OnlineConfig::default();
+        update_config(&config);
+        return config;
+    };
+
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        nay!("Failed to read the online config, resetting it: {}", e);
+        // saved straight away, otherwise every read would hand out a new player id
+        let config = repair_config(&contents);
+        update_config(&config);
+        config
+    })
+}
+
+/// A default config for one that can't be read, keeping the player id if it can still be found
+fn repair_config(contents: &str) -> OnlineConfig {
+    let player_id = serde_json::from_str::<serde_json::Value>(contents).ok()
+        .and_then(|value| value.get("player_id")?.as_str().map(str::to_string));
+
+    match player_id {
+        Some(player_id) => OnlineConfig { player_id, ..Default::default() },
+        None => OnlineConfig::default(),
+    }
+}
+
+pub fn update_config(config: &OnlineConfig) {
+    write_json(&get_config_path(), config);
+}
+
+/// A submission ready to post, `body` is sent exactly as it was signed
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SignedSubmission {
+    pub body: String,
+    pub signature: String,
+}
+
+impl SignedSubmission {
+    pub fn new(submission: &ScoreSubmission, secret: &str) -> Self {
+        let body = serde_json::to_string(submission).unwrap();
+        let signature = sign(body.as_bytes(), secret);
+        Self { body, signature }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OnlineError {
+    /// the server couldn't be reached or is having trouble, worth trying again later
+    Unreachable(String),
+    /// the server turned the request down, sending it again won't help
+    Rejected { status: u16, reason: String },
+    /// the save couldn't be read, e.g. while the game was writing it
+    Save(String),
+}
+
+impl Display for OnlineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OnlineError::Unreachable(reason) => write!(f, "Couldn't reach the leaderboard: {}", reason),
+            OnlineError::Rejected { status, reason } => write!(f, "The leaderboard refused the scores ({}): {}", status, reason),
+            OnlineError::Save(reason) => write!(f, "Couldn't read your save: {}", reason),
+        }
+    }
+}
+
+impl From<ureq::Error> for OnlineError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) if status < 500 => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = serde_json::from_str::<ErrorResponse>(&body).map_or(body, |r| r.error);
+                OnlineError::Rejected { status, reason }
+            }
+            e => OnlineError::Unreachable(e.to_string()),
+        }
+    }
+}
+
+fn url(endpoint: &str, path: &str) -> String {
+    format!("{}{}", endpoint.trim_end_matches('/'), path)
+}
+
+pub fn post_submission(endpoint: &str, submission: &SignedSubmission) -> Result<(), OnlineError> {
+    ureq::post(&url(endpoint, "/scores"))
+        .timeout(REQUEST_TIMEOUT)
+        .set("Content-Type", "application/json")
+        .set(SIGNATURE_HEADER, &submission.signature)
+        .send_string(&submission.body)?;
+    Ok(())
+}
+
+pub fn fetch_leaderboard(endpoint: &str) -> Result<Leaderboard, OnlineError> {
+    let response = ureq::get(&url(endpoint, "/leaderboard"))
+        .timeout(REQUEST_TIMEOUT)
+        .call()?;
+    response.into_json().map_err(|e| OnlineError::Unreachable(e.to_string()))
+}
+
+/// The player's current scores, from their save and catch history
+pub fn collect_scores(userfile: &UserValues, fish_data: &FishData, history: &[CatchRecord]) -> Scores {
+    let mut biggest_fish: Vec<BiggestFish> = userfile.records.iter()
+        .filter(|(_, record)| record.caught > 0)
+        .map(|(id, record)| BiggestFish {
+            species: id.clone(),
+            name: fish_data.fish.get(id).map_or(id.clone(), |fish| fish.to_string()),
+            weight: record.heaviest,
+        })
+        .collect();
+    biggest_fish.sort_by(|a, b| a.species.cmp(&b.species));
+
+    Scores {
+        biggest_fish,
+        total_value: history.iter()
+            .filter(|record| record.outcome == CatchOutcome::Caught)
+            .map(|record| record.value)
+            .sum::<Money>()
+            .dollars(),
+        species_seen: fish_data.regular_seen(&userfile.has_seen) as u32,
+        species_total: fish_data.regular_fish().count() as u32,
+    }
+}
+
+/// Signed submissions waiting to be sent, saved so they survive the game closing
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Outbox {
+    /// sequence number of the newest submission
+    pub sequence: u64,
+    /// oldest first
+    pub queue: Vec<SignedSubmission>,
+}
+
+impl Outbox {
+    /// Signs a snapshot of the scores and queues it
+    pub fn push(&mut self, config: &OnlineConfig, scores: Scores) {
+        let now = Local::now().timestamp();
+        // never behind the clock, so losing the outbox doesn't leave every new submission looking stale
+        self.sequence = (self.sequence + 1).max(now as u64);
+
+        let submission = ScoreSubmission {
+            player_id: config.player_id.clone(),
+            name: config.name.trim().to_string(),
+            sequence: self.sequence,
+            submitted_at: now,
+            scores,
+        };
+        self.queue.push(SignedSubmission::new(&submission, &config.secret));
+        if self.queue.len() > MAX_QUEUED {
+            self.queue.remove(0);
+        }
+    }
+
+    /// Posts the queue oldest first and returns how many were accepted,
+    /// refused submissions are dropped and an unreachable server leaves the rest queued
+    pub fn flush(&mut self, endpoint: &str) -> Result<usize, OnlineError> {
+        let mut sent = 0;
+        let mut refused = None;
+        while let Some(submission) = self.queue.first() {
+            match post_submission(endpoint, submission) {
+                Ok(()) => sent += 1,
+                Err(e @ OnlineError::Rejected { .. }) => {
+                    nay!("{}", e);
+                    refused = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+            self.queue.remove(0);
+        }
+
+        match refused {
+            Some(e) if sent == 0 => Err(e),
+            _ => Ok(sent),
+        }
+    }
+}
+
+pub fn get_outbox_path() -> String {
+    "./data/online_outbox.json".to_string()
+}
+
+pub fn read_outbox() -> Outbox {
+    std::fs::read_to_string(get_outbox_path()).ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub fn update_outbox(outbox: &Outbox) {
+    write_json(&get_outbox_path(), outbox);
+}
+
+fn write_json<T: Serialize>(raw_path: &str, value: &T) {
+    let path = Path::new(raw_path);
+    if let Err(e) = std::fs::create_dir_all(path.parent().unwrap()) {
+        nay!("Failed to create online directories: {}", e);
+        return;
+    }
+
+    let serialized = serde_json::to_string(value).unwrap();
+    if let Err(e) = std::fs::write(path, serialized) {
+        nay!("Failed to write {}: {}", raw_path, e);
+    }
+}
+
+/// What the game knows about its submissions
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStatus {
+    /// submissions waiting to be sent
+    pub queued: usize,
+    /// timestamp of the last accepted submission
+    pub last_sent: Option<i64>,
+    /// the last thing that went wrong, cleared once everything works again
+    pub error: Option<String>,
+    pub leaderboard: Option<Leaderboard>,
+}
+
+/// Submits scores in the background while they're enabled, retrying with backoff when it can't
+pub struct Uploader {
+    status: Arc<Mutex<OnlineStatus>>,
+    wake: Sender<()>,
+}
+
+impl Uploader {
+    pub fn start(fish_data: FishData) -> Self {
+        let status = Arc::new(Mutex::new(OnlineStatus {
+            queued: read_outbox().queue.len(),
+            ..Default::default()
+        }));
+        let (wake, woken) = channel();
+
+        let uploader_status = status.clone();
+        std::thread::spawn(move || {
+            let mut last_scores = None;
+            let mut wait = Duration::ZERO;
+            let mut retry = RETRY_MIN;
+            loop {
+                // being woken up means the player asked for it, so the scores go out even if unchanged
+                let forced = match woken.recv_timeout(wait) {
+                    Ok(()) => true,
+                    Err(RecvTimeoutError::Timeout) => false,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                };
+
+                let config = read_config();
+                if !config.enabled {
+                    wait = SUBMIT_INTERVAL;
+                    continue;
+                }
+
+                wait = match sync(&config, &fish_data, &mut last_scores, forced, &uploader_status) {
+                    Ok(()) => {
+                        retry = RETRY_MIN;
+                        SUBMIT_INTERVAL
+                    }
+                    Err(e) => {
+                        uploader_status.lock().unwrap().error = Some(e.to_string());
+                        let wait = retry;
+                        retry = (retry * 2).min(RETRY_MAX);
+                        wait
+                    }
+                };
+            }
+        });
+
+        Self { status, wake }
+    }
+
+    /// Submits and refreshes the leaderboard now instead of waiting
+    pub fn sync_now(&self) {
+        // the uploader only stops once this is dropped
+        let _ = self.wake.send(());
+    }
+
+    pub fn status(&self) -> OnlineStatus {
+        self.status.lock().unwrap().clone()
+    }
+}
+
+/// Queues the scores if they've changed, sends the queue and fetches the leaderboard
+fn sync(config: &OnlineConfig, fish_data: &FishData, last_scores: &mut Option<Scores>, forced: bool,
+        status: &Mutex<OnlineStatus>) -> Result<(), OnlineError> {
+    let userfile = try_read_userfile().map_err(|e| OnlineError::Save(e.to_string()))?;
+    let scores = collect_scores(&userfile, fish_data, &read_history());
+    let mut outbox = read_outbox();
+    if forced || last_scores.as_ref() != Some(&scores) {
+        outbox.push(config, scores.clone());
+        *last_scores = Some(scores);
+    }
+
+    let sent = outbox.flush(&config.endpoint);
+    update_outbox(&outbox);
+    {
+        let mut status = status.lock().unwrap();
+        status.queued = outbox.queue.len();
+        if sent.as_ref().is_ok_and(|sent| *sent > 0) {
+            status.last_sent = Some(Local::now().timestamp());
+        }
+    }
+    sent?;
+
+    let leaderboard = fetch_leaderboard(&config.endpoint)?;
+    let mut status = status.lock().unwrap();
+    status.leaderboard = Some(leaderboard);
+    status.error = None;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use super::*;
+    use crate::online::server::ScoreServer;
+
+    /// Starts a server on a free localhost port, returning its endpoint
+    fn start_server() -> String {
+        let server = ScoreServer::bind("127.0.0.1:0", DEV_SECRET).unwrap();
+        let endpoint = format!("http://{}", server.local_addr().unwrap());
+        std::thread::spawn(move || server.run());
+        endpoint
+    }
+
+    fn config(name: &str, endpoint: &str) -> OnlineConfig {
+        OnlineConfig {
+            enabled: true,
+            endpoint: endpoint.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn scores(species: &str, weight: f32, total_value: u64, species_seen: u32) -> Scores {
+        Scores {
+            biggest_fish: vec![BiggestFish { species: species.to_lowercase(), name: species.to_string(), weight }],
+            total_value,
+            species_seen,
+            species_total: 20,
+        }
+    }
+
+    #[test]
+    fn submissions_reach_the_leaderboard() {
+        let endpoint = start_server();
+        let alice = config("Alice", &endpoint);
+        let bob = config("Bob", &endpoint);
+
+        let mut outbox = Outbox::default();
+        outbox.push(&alice, scores("Bass", 4.5, 300, 5));
+        outbox.push(&bob, scores("Bass", 6.0, 100, 9));
+        assert_eq!(outbox.flush(&endpoint).unwrap(), 2);
+        assert!(outbox.queue.is_empty());
+
+        let leaderboard = fetch_leaderboard(&endpoint).unwrap();
+        assert_eq!(leaderboard.biggest_fish.len(), 1);
+        assert_eq!(leaderboard.biggest_fish[0].player, "Bob");
+        assert_eq!(leaderboard.biggest_fish[0].weight, 6.0);
+        assert_eq!(leaderboard.total_value[0].player, "Alice");
+        assert_eq!(leaderboard.total_value[0].total_value, 300);
+        assert_eq!(leaderboard.bestiary[0].player, "Bob");
+    }
+
+    #[test]
+    fn newer_submissions_replace_older_ones() {
+        let endpoint = start_server();
+        let alice = config("Alice", &endpoint);
+
+        let mut outbox = Outbox::default();
+        outbox.push(&alice, scores("Bass", 4.5, 300, 5));
+        outbox.push(&alice, scores("Bass", 5.0, 450, 6));
+        assert_eq!(outbox.flush(&endpoint).unwrap(), 2);
+
+        let leaderboard = fetch_leaderboard(&endpoint).unwrap();
+        assert_eq!(leaderboard.total_value.len(), 1);
+        assert_eq!(leaderboard.total_value[0].total_value, 450);
+    }
+
+    #[test]
+    fn replayed_submissions_are_rejected() {
+        let endpoint = start_server();
+        let mut outbox = Outbox::default();
+        outbox.push(&config("Alice", &endpoint), scores("Bass", 4.5, 300, 5));
+        let submission = outbox.queue[0].clone();
+
+        post_submission(&endpoint, &submission).unwrap();
+        let err = post_submission(&endpoint, &submission).unwrap_err();
+        assert!(matches!(err, OnlineError::Rejected { status: 409, .. }), "{:?}", err);
+    }
+
+    #[test]
+    fn wrongly_signed_submissions_are_dropped() {
+        let endpoint = start_server();
+        let mallory = OnlineConfig {
+            secret: "not the secret".to_string(),
+            ..config("Mallory", &endpoint)
+        };
+
+        let mut outbox = Outbox::default();
+        outbox.push(&mallory, scores("Bass", 999.0, 999_999, 20));
+        let err = outbox.flush(&endpoint).unwrap_err();
+        assert!(matches!(err, OnlineError::Rejected { status: 401, .. }), "{:?}", err);
+        assert!(outbox.queue.is_empty());
+
+        // tampering with a properly signed body breaks the signature too
+        outbox.push(&config("Mallory", &endpoint), scores("Bass", 1.0, 10, 1));
+        let mut tampered = outbox.queue.pop().unwrap();
+        tampered.body = tampered.body.replace("\"total_value\":10", "\"total_value\":999999");
+        let err = post_submission(&endpoint, &tampered).unwrap_err();
+        assert!(matches!(err, OnlineError::Rejected { status: 401, .. }), "{:?}", err);
+
+        assert!(fetch_leaderboard(&endpoint).unwrap().total_value.is_empty());
+    }
+
+    #[test]
+    fn repaired_configs_keep_the_player_id() {
+        let repaired = repair_config(r#"{"enabled": "yes", "player_id": "0123456789abcdef"}"#);
+        assert_eq!(repaired.player_id, "0123456789abcdef");
+        assert!(!repaired.enabled);
+
+        // nothing to keep from a file that isn't json at all
+        assert_eq!(repair_config("{\"player_id\": \"0123").player_id.len(), 16);
+    }
+
+    #[test]
+    fn offline_submissions_are_queued_until_they_can_be_sent() {
+        // nothing listens on a port that was just freed
+        let closed = format!("http://{}", TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap());
+        let alice = config("Alice", &closed);
+
+        let mut outbox = Outbox::default();
+        outbox.push(&alice, scores("Bass", 4.5, 300, 5));
+        outbox.push(&alice, scores("Pike", 9.0, 500, 6));
+        let err = outbox.flush(&closed).unwrap_err();
+        assert!(matches!(err, OnlineError::Unreachable(_)), "{:?}", err);
+        assert_eq!(outbox.queue.len(), 2);
+
+        // the queue survives being saved and loaded
+        let mut outbox: Outbox = serde_json::from_str(&serde_json::to_string(&outbox).unwrap()).unwrap();
+
+        let endpoint = start_server();
+        assert_eq!(outbox.flush(&endpoint).unwrap(), 2);
+        assert!(outbox.queue.is_empty());
+        assert_eq!(fetch_leaderboard(&endpoint).unwrap().total_value[0].total_value, 500);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod client;
+pub mod protocol;
+/// the game never serves scores itself, the server is built for `leaderboard_server` and the tests
+#[cfg(test)]
+pub mod server;
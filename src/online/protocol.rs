@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// the secret the reference server and a fresh config share, real servers hand out their own
+pub const DEV_SECRET: &str = "mini-fisher-dev-secret";
+pub const DEFAULT_PORT: u16 = 8080;
+/// request header carrying the hex HMAC-SHA256 of the body
+pub const SIGNATURE_HEADER: &str = "X-Signature";
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BiggestFish {
+    /// species id
+    pub species: String,
+    /// display name at the time of submission
+    pub name: String,
+    pub weight: f32,
+}
+
+/// The scores a player is ranked on
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Scores {
+    pub biggest_fish: Vec<BiggestFish>,
+    /// dollars earned from every fish ever caught
+    pub total_value: u64,
+    pub species_seen: u32,
+    pub species_total: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScoreSubmission {
+    /// random id picked the first time the game goes online
+    pub player_id: String,
+    pub name: String,
+    /// increases with every submission, servers refuse anything not newer than what they have
+    pub sequence: u64,
+    pub submitted_at: i64,
+    pub scores: Scores,
+}
+
+/// Hex HMAC-SHA256 of `body`
+pub fn sign(body: &[u8], secret: &str) -> String {
+    hmac_sha256::HMAC::mac(body, secret.as_bytes()).iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SpeciesLeader {
+    pub species: String,
+    pub player: String,
+    pub weight: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ValueLeader {
+    pub player: String,
+    pub total_value: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct BestiaryLeader {
+    pub player: String,
+    pub species_seen: u32,
+    pub species_total: u32,
+}
+
+/// What `GET /leaderboard` answers with, each list sorted best first
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct Leaderboard {
+    pub biggest_fish: Vec<SpeciesLeader>,
+    pub total_value: Vec<ValueLeader>,
+    pub bestiary: Vec<BestiaryLeader>,
+}
+
+/// The body of any response that isn't a success
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
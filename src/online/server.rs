@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::net::SocketAddr;
+use tiny_http::{Header, Method, Request, Response, Server};
+use super::protocol::{BestiaryLeader, ErrorResponse, Leaderboard, ScoreSubmission, sign, SIGNATURE_HEADER, SpeciesLeader, ValueLeader};
+
+/// submissions bigger than this are refused without being read
+const MAX_BODY: u64 = 64 * 1024;
+/// how many players are listed for total value and bestiary completion
+const MAX_LEADERS: usize = 10;
+
+/// The latest accepted submission from each player
+#[derive(Default)]
+struct Scoreboard {
+    /// player id -> submission
+    players: HashMap<String, ScoreSubmission>,
+}
+
+impl Scoreboard {
+    fn submit(&mut self, submission: ScoreSubmission) -> Result<(), (u16, String)> {
+        if submission.name.trim().is_empty() {
+            return Err((400, "Pick a name first".to_string()));
+        }
+        if let Some(previous) = self.players.get(&submission.player_id) {
+            if submission.sequence <= previous.sequence {
+                return Err((409, format!("Already have submission {} from {}", previous.sequence, previous.name)));
+            }
+        }
+
+        println!("{} submitted {} species, ${} and {}/{} seen", submission.name, submission.scores.biggest_fish.len(),
+                 submission.scores.total_value, submission.scores.species_seen, submission.scores.species_total);
+        self.players.insert(submission.player_id.clone(), submission);
+        Ok(())
+    }
+
+    fn leaderboard(&self) -> Leaderboard {
+        // species id -> heaviest fish anyone submitted
+        let mut biggest: HashMap<&str, SpeciesLeader> = HashMap::new();
+        for submission in self.players.values() {
+            for fish in &submission.scores.biggest_fish {
+                let is_bigger = biggest.get(fish.species.as_str()).is_none_or(|leader| fish.weight > leader.weight);
+                if is_bigger {
+                    biggest.insert(&fish.species, SpeciesLeader {
+                        species: fish.name.clone(),
+                        player: submission.name.clone(),
+                        weight: fish.weight,
+                    });
+                }
+            }
+        }
+        let mut biggest_fish: Vec<SpeciesLeader> = biggest.into_values().collect();
+        biggest_fish.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+
+        let mut total_value: Vec<ValueLeader> = self.players.values()
+            .map(|s| ValueLeader { player: s.name.clone(), total_value: s.scores.total_value })
+            .collect();
+        total_value.sort_by_key(|leader| std::cmp::Reverse(leader.total_value));
+        total_value.truncate(MAX_LEADERS);
+
+        let mut bestiary: Vec<BestiaryLeader> = self.players.values()
+            .map(|s| BestiaryLeader { player: s.name.clone(), species_seen: s.scores.species_seen, species_total: s.scores.species_total })
+            .collect();
+        bestiary.sort_by_key(|leader| std::cmp::Reverse(leader.species_seen));
+        bestiary.truncate(MAX_LEADERS);
+
+        Leaderboard { biggest_fish, total_value, bestiary }
+    }
+}
+
+/// A minimal leaderboard server, scores only live as long as it runs
+pub struct ScoreServer {
+    server: Server,
+    secret: String,
+    scoreboard: Scoreboard,
+}
+
+impl ScoreServer {
+    pub fn bind(addr: &str, secret: &str) -> std::io::Result<Self> {
+        let server = Server::http(addr).map_err(std::io::Error::other)?;
+        Ok(Self {
+            server,
+            secret: secret.to_string(),
+            scoreboard: Scoreboard::default(),
+        })
+    }
+
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Answers requests one at a time until the server fails
+    pub fn run(mut self) {
+        while let Ok(mut request) = self.server.recv() {
+            let (status, body) = self.handle(&mut request);
+            let json = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap();
+            let response = Response::from_string(body).with_status_code(status).with_header(json);
+            if let Err(e) = request.respond(response) {
+                eprintln!("Failed to answer a request: {}", e);
+            }
+        }
+    }
+
+    fn handle(&mut self, request: &mut Request) -> (u16, String) {
+        match (request.method(), request.url()) {
+            (Method::Post, "/scores") => match self.receive_submission(request) {
+                Ok(()) => (200, "{}".to_string()),
+                Err((status, error)) => (status, serde_json::to_string(&ErrorResponse { error }).unwrap()),
+            },
+            (Method::Get, "/leaderboard") => (200, serde_json::to_string(&self.scoreboard.leaderboard()).unwrap()),
+            _ => (404, serde_json::to_string(&ErrorResponse { error: "Not found".to_string() }).unwrap()),
+        }
+    }
+
+    fn receive_submission(&mut self, request: &mut Request) -> Result<(), (u16, String)> {
+        if request.body_length().is_some_and(|len| len as u64 > MAX_BODY) {
+            return Err((413, "Submission is too big".to_string()));
+        }
+        let mut body = Vec::new();
+        request.as_reader().take(MAX_BODY).read_to_end(&mut body)
+            .map_err(|e| (400, format!("Failed to read the submission: {}", e)))?;
+
+        let signature = request.headers().iter()
+            .find(|header| header.field.equiv(SIGNATURE_HEADER))
+            .map(|header| header.value.as_str().to_string())
+            .unwrap_or_default();
+        if !constant_time_eq(sign(&body, &self.secret).as_bytes(), signature.as_bytes()) {
+            return Err((401, "Bad signature".to_string()));
+        }
+
+        let submission = serde_json::from_slice(&body)
+            .map_err(|e| (400, format!("Malformed submission: {}", e)))?;
+        self.scoreboard.submit(submission)
+    }
+}
+
+/// Compares signatures without giving away how much of them matched
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}